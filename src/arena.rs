@@ -0,0 +1,232 @@
+//! 共享同一块存储的多个短链表
+use crate::{Link, LinkBuilder};
+use alloc::vec::Vec;
+use core::mem;
+
+/// LinkArena中一个链表的标识，reset之后失效
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ListId {
+    index: usize,
+    epoch: u32,
+}
+/// 存储中的槽位
+enum Slot<T> {
+    /// 空闲，记录下一个空闲槽位
+    Vacant(Option<usize>),
+    /// 已占用
+    Occupied {value: T, next: Option<usize>},
+}
+/// 链表的头部和长度
+#[derive(Clone, Copy)]
+struct Head {
+    first: Option<usize>,
+    len: usize,
+}
+/// 多个链表共用的结点存储，结点保存在一个连续增长的Vec中，
+/// 释放的槽位串成空闲链表供之后复用，只在存储扩容时才分配内存，
+/// reset一次性清空全部链表并保留已分配的容量
+/// # 例子
+/// ```
+/// use link::arena::LinkArena;
+/// // 用8个桶的链表实现简单的哈希集合
+/// let mut arena = LinkArena::with_capacity(64);
+/// let buckets: Vec<_> = (0..8).map(|_| arena.new_list()).collect();
+/// for v in [3u32, 11, 4, 19, 3] {
+///     let b = buckets[v as usize % 8];
+///     if !arena.iter(b).any(|x| *x == v) {
+///         arena.push(b, v);
+///     }
+/// }
+/// assert_eq!(arena.iter(buckets[3]).collect::<Vec<_>>(), [&19, &11, &3]);
+/// assert_eq!(arena.remove_first(buckets[3], |x| *x == 11), Some(11));
+/// assert_eq!(arena.node_count(), 3);
+/// arena.reset();
+/// assert_eq!(arena.node_count(), 0);
+/// assert!(arena.capacity() >= 64);
+/// ```
+pub struct LinkArena<T> {
+    slots: Vec<Slot<T>>,
+    heads: Vec<Head>,
+    // 已释放的空闲槽位组成的链表
+    free: Option<usize>,
+    nodes: usize,
+    epoch: u32,
+}
+impl<T> LinkArena<T> {
+    /// 创建空的存储
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+    /// 创建预留了n个结点空间的存储
+    pub fn with_capacity(n: usize) -> Self {
+        LinkArena {slots: Vec::with_capacity(n), heads: Vec::new(), free: None, nodes: 0, epoch: 0}
+    }
+    /// 不扩容时能容纳的结点个数
+    pub fn capacity(&self) -> usize {
+        self.slots.capacity()
+    }
+    /// 全部链表的结点总数
+    pub fn node_count(&self) -> usize {
+        self.nodes
+    }
+    /// 链表个数
+    pub fn list_count(&self) -> usize {
+        self.heads.len()
+    }
+    /// 创建一个新的空链表
+    pub fn new_list(&mut self) -> ListId {
+        self.heads.push(Head {first: None, len: 0});
+        ListId {index: self.heads.len() - 1, epoch: self.epoch}
+    }
+    /// 清空全部链表和结点，保留已分配的容量，之前的ListId全部失效
+    pub fn reset(&mut self) {
+        self.slots.clear();
+        self.heads.clear();
+        self.free = None;
+        self.nodes = 0;
+        self.epoch = self.epoch.wrapping_add(1);
+    }
+    fn head(&self, id: ListId) -> &Head {
+        assert!(id.epoch == self.epoch, "stale ListId");
+        &self.heads[id.index]
+    }
+    fn head_mut(&mut self, id: ListId) -> &mut Head {
+        assert!(id.epoch == self.epoch, "stale ListId");
+        &mut self.heads[id.index]
+    }
+    fn node(&self, i: usize) -> (&T, Option<usize>) {
+        match &self.slots[i] {
+            Slot::Occupied {value, next} => (value, *next),
+            Slot::Vacant(_) => unreachable!(),
+        }
+    }
+    /// 放入结点，优先复用空闲槽位
+    fn alloc(&mut self, value: T, next: Option<usize>) -> usize {
+        self.nodes += 1;
+        let slot = Slot::Occupied {value, next};
+        match self.free {
+            Some(i) => {
+                if let Slot::Vacant(f) = mem::replace(&mut self.slots[i], slot) {
+                    self.free = f;
+                }
+                i
+            },
+            None => {
+                self.slots.push(slot);
+                self.slots.len() - 1
+            },
+        }
+    }
+    /// 释放结点，返回它的值和后继
+    fn release(&mut self, i: usize) -> (T, Option<usize>) {
+        self.nodes -= 1;
+        match mem::replace(&mut self.slots[i], Slot::Vacant(self.free)) {
+            Slot::Occupied {value, next} => {
+                self.free = Some(i);
+                (value, next)
+            },
+            Slot::Vacant(_) => unreachable!(),
+        }
+    }
+    /// 获取链表长度，O(1)
+    /// # Panics
+    /// id已因reset失效时恐慌，下同
+    pub fn len(&self, id: ListId) -> usize {
+        self.head(id).len
+    }
+    /// 判断链表是否为空
+    pub fn is_empty(&self, id: ListId) -> bool {
+        self.head(id).first.is_none()
+    }
+    /// 在链表的头部压入一个元素
+    pub fn push(&mut self, id: ListId, val: T) {
+        let first = self.head(id).first;
+        let n = self.alloc(val, first);
+        let head = self.head_mut(id);
+        head.first = Some(n);
+        head.len += 1;
+    }
+    /// 弹出第一个元素，当链表为空时返回None
+    pub fn pop(&mut self, id: ListId) -> Option<T> {
+        let first = self.head(id).first?;
+        let (value, next) = self.release(first);
+        let head = self.head_mut(id);
+        head.first = next;
+        head.len -= 1;
+        Some(value)
+    }
+    /// 获取第一个元素的不可变引用
+    pub fn front(&self, id: ListId) -> Option<&T> {
+        Some(self.node(self.head(id).first?).0)
+    }
+    /// 删除第一个满足条件的元素并返回它
+    pub fn remove_first<F>(&mut self, id: ListId, mut pred: F) -> Option<T>
+    where
+        F: FnMut(&T) -> bool {
+        let mut prev = None;
+        let mut cur = self.head(id).first;
+        while let Some(i) = cur {
+            let (value, next) = self.node(i);
+            if pred(value) {
+                let (value, next) = self.release(i);
+                match prev {
+                    Some(p) => {
+                        if let Slot::Occupied {next: n, ..} = &mut self.slots[p] {
+                            *n = next;
+                        }
+                    },
+                    None => self.head_mut(id).first = next,
+                }
+                self.head_mut(id).len -= 1;
+                return Some(value);
+            }
+            prev = cur;
+            cur = next;
+        }
+        None
+    }
+    /// 清空一个链表，它的结点留给之后复用
+    pub fn clear(&mut self, id: ListId) {
+        while self.pop(id).is_some() {}
+    }
+    /// 把一个链表的元素移出为独立的Link，原链表变为空
+    /// # 例子
+    /// ```
+    /// use link::arena::LinkArena;
+    /// let mut arena = LinkArena::new();
+    /// let id = arena.new_list();
+    /// arena.push(id, 2);
+    /// arena.push(id, 1);
+    /// assert_eq!(format!("{:?}", arena.take(id)), "[1, 2]");
+    /// assert!(arena.is_empty(id));
+    /// ```
+    pub fn take(&mut self, id: ListId) -> Link<T> {
+        let mut builder = LinkBuilder::new();
+        while let Some(v) = self.pop(id) {
+            builder.push(v);
+        }
+        builder.finish()
+    }
+    /// 生成一个链表的不可变迭代器
+    pub fn iter(&self, id: ListId) -> ArenaIter<'_, T> {
+        ArenaIter {arena: self, cur: self.head(id).first}
+    }
+}
+impl<T> Default for LinkArena<T> {
+    fn default() -> Self {
+        LinkArena::new()
+    }
+}
+/// LinkArena中一个链表的不可变迭代器
+pub struct ArenaIter<'a, T> {
+    arena: &'a LinkArena<T>,
+    cur: Option<usize>,
+}
+impl<'a, T> Iterator for ArenaIter<'a, T> {
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (value, next) = self.arena.node(self.cur?);
+        self.cur = next;
+        Some(value)
+    }
+}
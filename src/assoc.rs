@@ -0,0 +1,147 @@
+//! 允许重复键的有序关联链表
+use crate::{Iter, Link, LinkBuilder, Node};
+use core::borrow::Borrow;
+use core::fmt;
+use core::iter::FromIterator;
+
+/// 保存键值对的关联链表，同一个键可以出现多次，适合保存HTTP头部之类的数据，
+/// 查找为O(n)，只要求键实现PartialEq。
+///
+/// insert在头部压入，为O(1)，因此链表按插入的逆序排列：最近插入的在最前面，
+/// 同一个键的新值遮蔽旧值，get和remove_first作用于最近插入的键值对。
+/// 需要按插入顺序处理时，把into_inner得到的链表反转
+/// # 例子
+/// ```
+/// use link::assoc::MultiAssocLink;
+/// let mut headers = MultiAssocLink::new();
+/// headers.insert("Accept", "text/html");
+/// headers.insert("Cookie", "a=1");
+/// headers.insert("Cookie", "b=2");
+/// assert_eq!(headers.get_all("Cookie").collect::<Vec<_>>(), [&"b=2", &"a=1"]);
+/// assert_eq!(headers.get("Cookie"), Some(&"b=2"));
+/// assert_eq!(headers.remove_first("Cookie"), Some("b=2"));
+/// assert_eq!(format!("{:?}", headers), r#"{"Cookie": "a=1", "Accept": "text/html"}"#);
+/// ```
+pub struct MultiAssocLink<K, V> {
+    link: Link<(K, V)>,
+}
+impl<K, V> MultiAssocLink<K, V> {
+    /// 创建空链表
+    pub fn new() -> Self {
+        MultiAssocLink {link: Link::new()}
+    }
+    /// 获取键值对个数
+    pub fn len(&self) -> usize {
+        self.link.len()
+    }
+    /// 判断是否为空
+    pub fn is_empty(&self) -> bool {
+        self.link.is_empty()
+    }
+    /// 在头部压入键值对，不检查键是否已存在，O(1)
+    pub fn insert(&mut self, k: K, v: V) {
+        self.link.push((k, v));
+    }
+    /// 从最近插入的开始遍历全部键值对
+    pub fn iter(&self) -> Iter<'_, (K, V)> {
+        self.link.iter()
+    }
+    /// 取出内部链表
+    pub fn into_inner(self) -> Link<(K, V)> {
+        self.link
+    }
+    /// 获取键最近一次插入的值
+    pub fn get<Q>(&self, k: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + ?Sized {
+        self.link.iter().find(|(key, _)| key.borrow() == k).map(|(_, v)| v)
+    }
+    /// 从最近插入的开始获取键对应的全部值
+    pub fn get_all<'a, Q>(&'a self, k: &'a Q) -> impl Iterator<Item = &'a V> + 'a
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + ?Sized {
+        self.link.iter().filter(move |(key, _)| key.borrow() == k).map(|(_, v)| v)
+    }
+    /// 判断键是否存在
+    pub fn contains_key<Q>(&self, k: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + ?Sized {
+        self.get(k).is_some()
+    }
+    /// 删除键最近一次插入的键值对并返回它的值
+    pub fn remove_first<Q>(&mut self, k: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + ?Sized {
+        let mut node = &mut self.link;
+        while let Some(n) = node.0.as_ref() {
+            if n.value.0.borrow() == k {
+                let Node {value, next} = *node.0.take().unwrap();
+                *node = next;
+                return Some(value.1);
+            }
+            node = &mut node.0.as_mut().unwrap().next;
+        }
+        None
+    }
+    /// 删除键的全部键值对，从最近插入的开始返回被删的值
+    /// # 例子
+    /// ```
+    /// use link::assoc::MultiAssocLink;
+    /// let mut m: MultiAssocLink<_, _> = vec![("a", 1), ("b", 2), ("a", 3)].into_iter().collect();
+    /// assert_eq!(format!("{:?}", m.remove_all("a")), "[3, 1]");
+    /// assert!(m.remove_all("a").is_empty());
+    /// assert_eq!(m.len(), 1);
+    /// ```
+    pub fn remove_all<Q>(&mut self, k: &Q) -> Link<V>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + ?Sized {
+        let mut removed = LinkBuilder::new();
+        let mut node = &mut self.link;
+        while let Some(n) = node.0.as_ref() {
+            if n.value.0.borrow() == k {
+                let Node {value, next} = *node.0.take().unwrap();
+                *node = next;
+                removed.push(value.1);
+            } else {
+                node = &mut node.0.as_mut().unwrap().next;
+            }
+        }
+        removed.finish()
+    }
+}
+impl<K, V> Default for MultiAssocLink<K, V> {
+    fn default() -> Self {
+        MultiAssocLink::new()
+    }
+}
+/// 链表的顺序原样保留，第一个键值对被视为最近插入的
+impl<K, V> From<Link<(K, V)>> for MultiAssocLink<K, V> {
+    fn from(link: Link<(K, V)>) -> Self {
+        MultiAssocLink {link}
+    }
+}
+/// 按迭代顺序逐个insert，最后一个键值对排在最前面
+impl<K, V> FromIterator<(K, V)> for MultiAssocLink<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut m = MultiAssocLink::new();
+        m.extend(iter);
+        m
+    }
+}
+impl<K, V> Extend<(K, V)> for MultiAssocLink<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (k, v) in iter {
+            self.insert(k, v);
+        }
+    }
+}
+impl<K: fmt::Debug, V: fmt::Debug> fmt::Debug for MultiAssocLink<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter().map(|(k, v)| (k, v))).finish()
+    }
+}
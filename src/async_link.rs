@@ -0,0 +1,93 @@
+//! 供异步任务共享的链表
+use crate::{IntoIter, Link};
+use std::sync::Arc;
+use tokio::sync::{Mutex, MutexGuard};
+
+/// 由tokio异步互斥锁保护的共享链表，克隆得到的AsyncLink指向同一个链表，
+/// 等待锁时不阻塞线程
+/// # 例子
+/// ```
+/// use link::AsyncLink;
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let work = AsyncLink::new();
+/// let producer = {
+///     let work = work.clone();
+///     tokio::spawn(async move {
+///         for i in 0..3 {
+///             work.push_back(i).await;
+///         }
+///     })
+/// };
+/// producer.await.unwrap();
+/// assert_eq!(work.iter_snapshot().await.collect::<Vec<_>>(), vec![0, 1, 2]);
+/// assert_eq!(work.pop().await, Some(0));
+/// assert_eq!(work.len().await, 2);
+/// # }
+/// ```
+pub struct AsyncLink<T> {
+    inner: Arc<Mutex<Link<T>>>,
+}
+impl<T> AsyncLink<T> {
+    /// 创建空链表
+    pub fn new() -> Self {
+        Self::from(Link::new())
+    }
+    /// 获取锁，可以在持有期间对链表进行任意操作
+    pub async fn lock(&self) -> MutexGuard<'_, Link<T>> {
+        self.inner.lock().await
+    }
+    /// 在链表的头部压入一个元素
+    pub async fn push(&self, val: T) {
+        self.lock().await.push(val);
+    }
+    /// 在链表的尾部追加元素
+    pub async fn push_back(&self, val: T) {
+        self.lock().await.push_back(val);
+    }
+    /// 弹出第一个元素，当链表为空时返回None
+    pub async fn pop(&self) -> Option<T> {
+        self.lock().await.pop()
+    }
+    /// 弹出最后一个元素，当链表为空时返回None
+    pub async fn pop_back(&self) -> Option<T> {
+        self.lock().await.pop_back()
+    }
+    /// 获取链表长度
+    pub async fn len(&self) -> usize {
+        self.lock().await.len()
+    }
+    /// 判断链表是否为空
+    pub async fn is_empty(&self) -> bool {
+        self.lock().await.is_empty()
+    }
+    /// 在持有锁期间把元素克隆为共享切片，得到一致的只读视图，
+    /// 之后的写入不影响快照
+    pub async fn snapshot(&self) -> Arc<[T]>
+    where
+        T: Clone {
+        self.lock().await.snapshot()
+    }
+    /// 在持有锁期间克隆整个链表，返回克隆的迭代器，迭代时不再持有锁
+    pub async fn iter_snapshot(&self) -> IntoIter<T>
+    where
+        T: Clone {
+        let snapshot = self.lock().await.clone();
+        snapshot.into_iter()
+    }
+}
+impl<T> Clone for AsyncLink<T> {
+    fn clone(&self) -> Self {
+        AsyncLink {inner: Arc::clone(&self.inner)}
+    }
+}
+impl<T> Default for AsyncLink<T> {
+    fn default() -> Self {
+        AsyncLink::new()
+    }
+}
+impl<T> From<Link<T>> for AsyncLink<T> {
+    fn from(link: Link<T>) -> Self {
+        AsyncLink {inner: Arc::new(Mutex::new(link))}
+    }
+}
@@ -0,0 +1,229 @@
+//! 供异步执行器和同步原语使用的等待队列
+use crate::Link;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::task::Waker;
+
+/// 空的槽位索引，表示没有前驱或后继
+const NIL: usize = usize::MAX;
+/// 等待者槽位，id为占用它的登记号，空闲时waker为None
+struct Slot {
+    id: u64,
+    waker: Option<Waker>,
+    prev: usize,
+    next: usize,
+}
+/// 等待者表，槽位保存在数组中，占用的槽位按登记顺序组成双向链表，
+/// 空闲槽位的索引保存在free中重复使用
+struct Waiters {
+    slots: Vec<Slot>,
+    free: Vec<usize>,
+    head: usize,
+    tail: usize,
+    len: usize,
+    next_id: u64,
+}
+impl Waiters {
+    /// 在链表末尾登记，返回槽位索引和登记号，均摊O(1)
+    fn push_back(&mut self, waker: Waker) -> (usize, u64) {
+        let id = self.next_id;
+        self.next_id += 1;
+        let slot = Slot {id, waker: Some(waker), prev: self.tail, next: NIL};
+        let i = match self.free.pop() {
+            Some(i) => {
+                self.slots[i] = slot;
+                i
+            }
+            None => {
+                self.slots.push(slot);
+                self.slots.len() - 1
+            }
+        };
+        match self.tail {
+            NIL => self.head = i,
+            t => self.slots[t].next = i,
+        }
+        self.tail = i;
+        self.len += 1;
+        (i, id)
+    }
+    /// 登记号为id的等待者仍在槽位i中时返回它的Waker
+    fn get_mut(&mut self, i: usize, id: u64) -> Option<&mut Waker> {
+        match self.slots.get_mut(i) {
+            Some(s) if s.id == id => s.waker.as_mut(),
+            _ => None,
+        }
+    }
+    /// 把槽位i从链表中摘下并释放，返回它的Waker，O(1)
+    fn unlink(&mut self, i: usize) -> Option<Waker> {
+        let waker = self.slots[i].waker.take()?;
+        let (prev, next) = (self.slots[i].prev, self.slots[i].next);
+        match prev {
+            NIL => self.head = next,
+            p => self.slots[p].next = next,
+        }
+        match next {
+            NIL => self.tail = prev,
+            n => self.slots[n].prev = prev,
+        }
+        self.free.push(i);
+        self.len -= 1;
+        Some(waker)
+    }
+}
+/// 按登记顺序保存等待中任务的Waker的等待队列，可以在多个线程之间共享。
+///
+/// register返回的WakerGuard在析构时把自己从队列中摘下，
+/// 被取消的Future不会在队列中留下失效的Waker。
+/// wake_one唤醒并移除最早登记的等待者，wake_all唤醒并移除全部等待者。
+/// 等待者保存在可重复使用槽位的双向链表中，register为均摊O(1)，
+/// wake_one和WakerGuard的各个操作为O(1)，wake_all为O(n)
+/// # 例子
+/// ```
+/// use std::sync::Arc;
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use std::task::{Wake, Waker};
+/// use link::async_queue::WakerList;
+/// struct Count(AtomicUsize);
+/// impl Wake for Count {
+///     fn wake(self: Arc<Self>) {
+///         self.0.fetch_add(1, Ordering::SeqCst);
+///     }
+/// }
+/// let wakes: Vec<_> = (0..3).map(|_| Arc::new(Count(AtomicUsize::new(0)))).collect();
+/// let queue = WakerList::new();
+/// let guards: Vec<_> = wakes.iter().map(|c| queue.register(Waker::from(Arc::clone(c)))).collect();
+/// assert_eq!(queue.len(), 3);
+/// assert!(queue.wake_one());
+/// assert!(guards[0].is_woken());
+/// // 第二个等待者被取消，析构时自动摘下
+/// drop(guards);
+/// assert!(queue.is_empty());
+/// assert_eq!(queue.wake_all(), 0);
+/// let counts: Vec<_> = wakes.iter().map(|c| c.0.load(Ordering::SeqCst)).collect();
+/// assert_eq!(counts, [1, 0, 0]);
+/// ```
+pub struct WakerList {
+    waiters: Mutex<Waiters>,
+}
+impl WakerList {
+    /// 创建空队列
+    pub fn new() -> Self {
+        WakerList {
+            waiters: Mutex::new(Waiters {slots: Vec::new(), free: Vec::new(), head: NIL, tail: NIL, len: 0, next_id: 0}),
+        }
+    }
+    /// 唤醒Waker不会使队列处于不一致的状态，锁中毒时继续使用
+    fn lock(&self) -> MutexGuard<'_, Waiters> {
+        self.waiters.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+    /// 在队尾登记一个等待者，返回的WakerGuard析构时把它从队列中摘下
+    pub fn register(&self, waker: Waker) -> WakerGuard<'_> {
+        let (slot, id) = self.lock().push_back(waker);
+        WakerGuard {queue: self, slot, id}
+    }
+    /// 等待中的任务个数
+    pub fn len(&self) -> usize {
+        self.lock().len
+    }
+    /// 判断是否没有等待中的任务
+    pub fn is_empty(&self) -> bool {
+        self.lock().len == 0
+    }
+    /// 唤醒并移除最早登记的等待者，队列为空时返回false。
+    /// Waker在释放锁之后调用
+    pub fn wake_one(&self) -> bool {
+        let first = {
+            let mut w = self.lock();
+            let head = w.head;
+            match head {
+                NIL => None,
+                i => w.unlink(i),
+            }
+        };
+        match first {
+            Some(waker) => {
+                waker.wake();
+                true
+            }
+            None => false,
+        }
+    }
+    /// 按登记顺序唤醒并移除全部等待者，返回唤醒的个数。
+    /// Waker在释放锁之后调用，被唤醒的任务可以立即重新登记
+    pub fn wake_all(&self) -> usize {
+        // 从队尾向前逐个压入链表头部，得到按登记顺序排列的Waker
+        let mut waiters = Link::new();
+        {
+            let mut w = self.lock();
+            while w.tail != NIL {
+                let tail = w.tail;
+                waiters.push(w.unlink(tail).unwrap());
+            }
+            w.free.clear();
+            w.slots.clear();
+        }
+        let mut n = 0;
+        for waker in waiters {
+            waker.wake();
+            n += 1;
+        }
+        n
+    }
+}
+impl Default for WakerList {
+    fn default() -> Self {
+        WakerList::new()
+    }
+}
+impl core::fmt::Debug for WakerList {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("WakerList").field("len", &self.len()).finish()
+    }
+}
+/// WakerList::register返回的登记凭证，析构时把等待者从队列中摘下
+/// # 例子
+/// ```
+/// use std::task::Waker;
+/// use link::async_queue::WakerList;
+/// let queue = WakerList::new();
+/// let guard = queue.register(Waker::noop().clone());
+/// assert!(!guard.is_woken());
+/// // 再次poll时换成新的Waker，保留原来的排队位置
+/// assert!(guard.update(Waker::noop()));
+/// assert_eq!(queue.wake_all(), 1);
+/// assert!(guard.is_woken());
+/// assert!(!guard.update(Waker::noop()));
+/// ```
+#[derive(Debug)]
+pub struct WakerGuard<'a> {
+    queue: &'a WakerList,
+    slot: usize,
+    id: u64,
+}
+impl WakerGuard<'_> {
+    /// 判断等待者是否已被唤醒（即已不在队列中）
+    pub fn is_woken(&self) -> bool {
+        self.queue.lock().get_mut(self.slot, self.id).is_none()
+    }
+    /// 替换登记的Waker，两者唤醒同一个任务时不做替换，已被唤醒时返回false
+    pub fn update(&self, waker: &Waker) -> bool {
+        let mut w = self.queue.lock();
+        match w.get_mut(self.slot, self.id) {
+            Some(old) => {
+                if !old.will_wake(waker) {
+                    old.clone_from(waker);
+                }
+                true
+            }
+            None => false,
+        }
+    }
+}
+impl Drop for WakerGuard<'_> {
+    fn drop(&mut self) {
+        let mut w = self.queue.lock();
+        if w.get_mut(self.slot, self.id).is_some() {
+            w.unlink(self.slot);
+        }
+    }
+}
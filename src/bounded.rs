@@ -0,0 +1,216 @@
+//! 有容量上限的链表
+use crate::{Link, Iter};
+use core::fmt;
+
+/// 链表已满时的处理策略
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Overflow {
+    /// 拒绝新元素，返回CapacityExceeded
+    Reject,
+    /// 从另一端淘汰旧元素
+    Evict,
+}
+/// 超出容量的错误，携带未能插入的元素
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct CapacityExceeded<T>(pub T);
+impl<T> fmt::Debug for CapacityExceeded<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("CapacityExceeded(..)")
+    }
+}
+impl<T> fmt::Display for CapacityExceeded<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("capacity exceeded for BoundedLink")
+    }
+}
+impl<T> core::error::Error for CapacityExceeded<T> {}
+/// 结构完整性检查发现的错误
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IntegrityError {
+    /// 缓存的长度与实际结点数不一致
+    LengthMismatch { cached: usize, actual: usize },
+    /// 实际长度超过容量
+    OverCapacity { len: usize, capacity: usize },
+}
+impl fmt::Display for IntegrityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IntegrityError::LengthMismatch {cached, actual} =>
+                write!(f, "cached length {} does not match actual length {}", cached, actual),
+            IntegrityError::OverCapacity {len, capacity} =>
+                write!(f, "length {} exceeds capacity {}", len, capacity),
+        }
+    }
+}
+impl core::error::Error for IntegrityError {}
+/// 有容量上限的链表
+/// # 例子
+/// ```
+/// use link::*;
+/// let mut l: BoundedLink<isize> = BoundedLink::with_policy(2, Overflow::Evict);
+/// l.push_back(1).unwrap();
+/// l.push_back(2).unwrap();
+/// assert_eq!(l.push_back(3), Ok(Some(1)));
+/// assert_eq!(format!("{:?}", l), "[2, 3]");
+/// ```
+#[derive(Clone)]
+pub struct BoundedLink<T> {
+    link: Link<T>,
+    len: usize,
+    capacity: usize,
+    policy: Overflow,
+}
+impl<T> BoundedLink<T> {
+    /// 创建容量为capacity的空链表，满时拒绝新元素
+    pub fn new(capacity: usize) -> Self {
+        Self::with_policy(capacity, Overflow::Reject)
+    }
+    /// 创建容量为capacity的空链表，并指定满时的处理策略
+    pub fn with_policy(capacity: usize, policy: Overflow) -> Self {
+        BoundedLink {link: Link::new(), len: 0, capacity, policy}
+    }
+    /// 获取容量
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+    /// 获取满时的处理策略
+    pub fn policy(&self) -> Overflow {
+        self.policy
+    }
+    /// 获取链表长度，O(1)
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    /// 判断链表是否为空
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    /// 判断链表是否已满
+    pub fn is_full(&self) -> bool {
+        self.len >= self.capacity
+    }
+    /// 按策略放入元素，evict_front表示已满时从头部淘汰，返回被淘汰的元素
+    fn put<F>(&mut self, val: T, evict_front: bool, f: F) -> Result<Option<T>, CapacityExceeded<T>>
+    where
+        F: FnOnce(&mut Link<T>, T) {
+        let mut old = None;
+        if self.is_full() {
+            match self.policy {
+                Overflow::Reject => return Err(CapacityExceeded(val)),
+                //容量为0时新元素直接被淘汰
+                Overflow::Evict if self.capacity == 0 => return Ok(Some(val)),
+                Overflow::Evict => {
+                    old = if evict_front { self.link.pop() } else { self.link.pop_back() };
+                    self.len -= 1;
+                },
+            }
+        }
+        f(&mut self.link, val);
+        self.len += 1;
+        Ok(old)
+    }
+    /// 在链表的头部压入一个元素，已满且策略为Evict时淘汰尾部元素并返回
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let mut l: BoundedLink<isize> = BoundedLink::new(1);
+    /// assert_eq!(l.push(1), Ok(None));
+    /// assert_eq!(l.push(2), Err(CapacityExceeded(2)));
+    /// ```
+    pub fn push(&mut self, val: T) -> Result<Option<T>, CapacityExceeded<T>> {
+        self.put(val, false, Link::push)
+    }
+    /// 在链表的尾部追加元素，已满且策略为Evict时淘汰头部元素并返回，与Link::push_back相同为O(n)
+    pub fn push_back(&mut self, val: T) -> Result<Option<T>, CapacityExceeded<T>> {
+        self.put(val, true, Link::push_back)
+    }
+    /// 在指定位置插入元素，已满且策略为Evict时：插入位置在尾部则淘汰头部元素，否则淘汰尾部元素
+    /// # Panics
+    /// 当i大于链表长度时恐慌
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let mut l: BoundedLink<isize> = BoundedLink::with_policy(3, Overflow::Evict);
+    /// l.push_back(0).unwrap();
+    /// l.push_back(1).unwrap();
+    /// l.push_back(2).unwrap();
+    /// assert_eq!(l.insert(1, 5), Ok(Some(2)));
+    /// assert_eq!(format!("{:?}", l), "[0, 5, 1]");
+    /// ```
+    pub fn insert(&mut self, i: usize, val: T) -> Result<Option<T>, CapacityExceeded<T>> {
+        if i > self.len {
+            Link::<T>::out_of_range(i, self.len);
+        }
+        if i == self.len {
+            self.push_back(val)
+        } else {
+            self.put(val, false, |l, v| { l.insert(i, v); })
+        }
+    }
+    /// 弹出第一个元素，当链表为空时返回None
+    pub fn pop(&mut self) -> Option<T> {
+        let v = self.link.pop()?;
+        self.len -= 1;
+        Some(v)
+    }
+    /// 弹出最后一个元素，当链表为空时返回None，与Link::pop_back相同为O(n)
+    pub fn pop_back(&mut self) -> Option<T> {
+        let v = self.link.pop_back()?;
+        self.len -= 1;
+        Some(v)
+    }
+    /// 在指定位置删除元素，返回被删元素，当删除失败时返回None
+    pub fn delete(&mut self, i: usize) -> Option<T> {
+        let v = self.link.delete(i)?;
+        self.len -= 1;
+        Some(v)
+    }
+    /// 获取链表的第一个元素的不可变引用
+    pub fn front(&self) -> Option<&T> {
+        self.link.front()
+    }
+    /// 获取链表的最后一个元素的不可变引用
+    pub fn back(&self) -> Option<&T> {
+        self.link.back()
+    }
+    /// 清空链表
+    pub fn clear(&mut self) {
+        self.link = Link::new();
+        self.len = 0;
+    }
+    /// 生成不可变迭代器
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.link.iter()
+    }
+    /// 遍历链表检查缓存的长度和容量上限，用于调试
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let mut l: BoundedLink<isize> = BoundedLink::new(2);
+    /// l.push(1).unwrap();
+    /// assert_eq!(l.validate(), Ok(()));
+    /// ```
+    pub fn validate(&self) -> Result<(), IntegrityError> {
+        let actual = self.link.len();
+        if actual != self.len {
+            return Err(IntegrityError::LengthMismatch {cached: self.len, actual});
+        }
+        if actual > self.capacity {
+            return Err(IntegrityError::OverCapacity {len: actual, capacity: self.capacity});
+        }
+        Ok(())
+    }
+    /// 查看内部链表
+    pub fn as_link(&self) -> &Link<T> {
+        &self.link
+    }
+    /// 转化为内部链表
+    pub fn into_inner(self) -> Link<T> {
+        self.link
+    }
+}
+impl<T: fmt::Debug> fmt::Debug for BoundedLink<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.link.fmt(f)
+    }
+}
@@ -0,0 +1,189 @@
+//! 与链表相同风格的二叉搜索树
+use crate::{Link, LinkBuilder};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::iter::FromIterator;
+
+/// 子树，与Link一样用Option<Box<..>>表示
+type Subtree<T> = Option<Box<TreeNode<T>>>;
+struct TreeNode<T> {
+    value: T,
+    left: Subtree<T>,
+    right: Subtree<T>,
+}
+/// 二叉搜索树，元素不重复，不做平衡，插入、查找和删除的复杂度与树高成正比
+/// # 例子
+/// ```
+/// use link::bst::Tree;
+/// let mut t = Tree::new();
+/// for v in [5, 2, 8, 1, 9, 3] {
+///     t.insert(v);
+/// }
+/// assert!(t.contains(&3));
+/// assert_eq!(t.remove(&5), Some(5));
+/// assert!(!t.insert(2));
+/// assert_eq!(t.len(), 5);
+/// assert_eq!(format!("{:?}", t.into_link()), "[1, 2, 3, 8, 9]");
+/// ```
+pub struct Tree<T> {
+    root: Subtree<T>,
+    len: usize,
+}
+impl<T: Ord> Tree<T> {
+    /// 创建空树
+    pub fn new() -> Self {
+        Tree {root: None, len: 0}
+    }
+    /// 获取元素个数，O(1)
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    /// 判断树是否为空
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+    /// 查找value应在的位置
+    fn slot_mut(&mut self, value: &T) -> &mut Subtree<T> {
+        let mut slot = &mut self.root;
+        while let Some(n) = slot.as_ref() {
+            slot = match value.cmp(&n.value) {
+                Ordering::Less => &mut slot.as_mut().unwrap().left,
+                Ordering::Greater => &mut slot.as_mut().unwrap().right,
+                Ordering::Equal => break,
+            };
+        }
+        slot
+    }
+    /// 插入元素，元素已存在时返回false且不修改树
+    pub fn insert(&mut self, value: T) -> bool {
+        let slot = self.slot_mut(&value);
+        if slot.is_some() {
+            return false;
+        }
+        *slot = Some(Box::new(TreeNode {value, left: None, right: None}));
+        self.len += 1;
+        true
+    }
+    /// 判断元素是否存在
+    pub fn contains(&self, value: &T) -> bool {
+        let mut node = self.root.as_deref();
+        while let Some(n) = node {
+            node = match value.cmp(&n.value) {
+                Ordering::Less => n.left.as_deref(),
+                Ordering::Greater => n.right.as_deref(),
+                Ordering::Equal => return true,
+            };
+        }
+        false
+    }
+    /// 删除元素，返回被删元素，元素不存在时返回None
+    pub fn remove(&mut self, value: &T) -> Option<T> {
+        let slot = self.slot_mut(value);
+        let mut node = slot.take()?;
+        *slot = match (node.left.take(), node.right.take()) {
+            (None, None) => None,
+            (Some(l), None) => Some(l),
+            (None, Some(r)) => Some(r),
+            (Some(l), Some(r)) => {
+                // 用右子树的最小结点代替被删结点
+                let mut right = Some(r);
+                let mut min = &mut right;
+                while min.as_ref().unwrap().left.is_some() {
+                    min = &mut min.as_mut().unwrap().left;
+                }
+                let mut m = min.take().unwrap();
+                *min = m.right.take();
+                m.left = Some(l);
+                m.right = right;
+                Some(m)
+            },
+        };
+        self.len -= 1;
+        Some(node.value)
+    }
+}
+impl<T> Tree<T> {
+    /// 生成中序（从小到大）遍历的迭代器
+    pub fn iter(&self) -> TreeIter<'_, T> {
+        let mut iter = TreeIter {stack: Vec::new()};
+        iter.push_left(self.root.as_deref());
+        iter
+    }
+    /// 按从小到大的顺序复制为链表
+    pub fn to_link(&self) -> Link<T>
+    where
+        T: Clone {
+        self.iter().cloned().collect()
+    }
+    /// 按从小到大的顺序转化为链表
+    pub fn into_link(mut self) -> Link<T> {
+        let mut builder = LinkBuilder::new();
+        let mut stack = Vec::new();
+        let mut cur = self.root.take();
+        loop {
+            while let Some(mut n) = cur {
+                cur = n.left.take();
+                stack.push(n);
+            }
+            match stack.pop() {
+                Some(mut n) => {
+                    cur = n.right.take();
+                    builder.push(n.value);
+                },
+                None => break,
+            }
+        }
+        builder.finish()
+    }
+}
+impl<T: Ord> Default for Tree<T> {
+    fn default() -> Self {
+        Tree::new()
+    }
+}
+/// 逐个释放结点，避免退化成链的树递归析构导致栈溢出
+impl<T> Drop for Tree<T> {
+    fn drop(&mut self) {
+        let mut stack: Vec<Box<TreeNode<T>>> = self.root.take().into_iter().collect();
+        while let Some(mut n) = stack.pop() {
+            stack.extend(n.left.take());
+            stack.extend(n.right.take());
+        }
+    }
+}
+impl<T: Ord> FromIterator<T> for Tree<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut t = Tree::new();
+        for v in iter {
+            t.insert(v);
+        }
+        t
+    }
+}
+impl<T: fmt::Debug> fmt::Debug for Tree<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+/// 中序遍历的迭代器
+pub struct TreeIter<'a, T> {
+    stack: Vec<&'a TreeNode<T>>,
+}
+impl<'a, T> TreeIter<'a, T> {
+    fn push_left(&mut self, mut node: Option<&'a TreeNode<T>>) {
+        while let Some(n) = node {
+            self.stack.push(n);
+            node = n.left.as_deref();
+        }
+    }
+}
+impl<'a, T> Iterator for TreeIter<'a, T> {
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        let n = self.stack.pop()?;
+        self.push_left(n.right.as_deref());
+        Some(&n.value)
+    }
+}
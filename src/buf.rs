@@ -0,0 +1,98 @@
+//! 与bytes库的集成，把Link<Bytes>当作不连续的缓冲区链
+use crate::Link;
+use bytes::{Buf, Bytes};
+
+/// 把Link<Bytes>当作缓冲区链读取，读完的块会从链表中弹出。
+///
+/// remaining每次调用都要遍历全部块，为O(块数)，而get_u8等逐字节读取的方法每次都会调用它，
+/// 逐字节读完整个链表为O(字节数 × 块数)；需要频繁读取时使用缓存剩余长度的BufChain
+/// # 例子
+/// ```
+/// use bytes::{Buf, Bytes};
+/// use link::*;
+/// let mut l: Link<Bytes> = link![Bytes::from_static(b"he"), Bytes::from_static(b"llo")];
+/// assert_eq!(l.remaining(), 5);
+/// assert_eq!(l.get_u8(), b'h');
+/// let mut out = [0; 4];
+/// Buf::copy_to_slice(&mut l, &mut out);
+/// assert_eq!(&out, b"ello");
+/// assert!(l.is_empty());
+/// ```
+impl Buf for Link<Bytes> {
+    fn remaining(&self) -> usize {
+        self.iter().map(Bytes::len).sum()
+    }
+    fn chunk(&self) -> &[u8] {
+        self.iter().find(|b| !b.is_empty()).map_or(&[], |b| &b[..])
+    }
+    fn advance(&mut self, mut cnt: usize) {
+        while cnt > 0 {
+            let front = match self.front_mut() {
+                Some(b) => b,
+                None => panic!("cannot advance past the end of the buffer chain"),
+            };
+            if front.len() > cnt {
+                front.advance(cnt);
+                return;
+            }
+            cnt -= front.len();
+            self.pop();
+        }
+        // 去掉已读完的空块
+        while self.front().is_some_and(Bytes::is_empty) {
+            self.pop();
+        }
+    }
+}
+/// 缓存剩余长度的缓冲区链，remaining为O(1)，其余与Link<Bytes>的Buf实现相同
+/// # 例子
+/// ```
+/// use bytes::{Buf, Bytes};
+/// use link::*;
+/// use link::buf::BufChain;
+/// let chunks: Link<Bytes> = (0..100u8).map(|i| Bytes::from(vec![i; 10])).collect();
+/// let mut chain = BufChain::new(chunks);
+/// assert_eq!(chain.remaining(), 1000);
+/// let mut sum = 0u32;
+/// while chain.has_remaining() {
+///     sum += chain.get_u8() as u32;
+/// }
+/// assert_eq!(sum, 49500);
+/// assert!(chain.into_inner().is_empty());
+/// ```
+pub struct BufChain {
+    chunks: Link<Bytes>,
+    remaining: usize,
+}
+impl BufChain {
+    /// 包装缓冲区链，计算一次剩余长度，O(块数)
+    pub fn new(chunks: Link<Bytes>) -> Self {
+        let remaining = chunks.remaining();
+        BufChain {chunks, remaining}
+    }
+    /// 查看尚未读取的块
+    pub fn get_ref(&self) -> &Link<Bytes> {
+        &self.chunks
+    }
+    /// 取出尚未读取的块
+    pub fn into_inner(self) -> Link<Bytes> {
+        self.chunks
+    }
+}
+impl From<Link<Bytes>> for BufChain {
+    fn from(chunks: Link<Bytes>) -> Self {
+        BufChain::new(chunks)
+    }
+}
+impl Buf for BufChain {
+    fn remaining(&self) -> usize {
+        self.remaining
+    }
+    fn chunk(&self) -> &[u8] {
+        self.chunks.chunk()
+    }
+    fn advance(&mut self, cnt: usize) {
+        self.chunks.advance(cnt);
+        self.remaining -= cnt;
+    }
+}
@@ -0,0 +1,57 @@
+//! 链表构建器
+use crate::Link;
+
+/// 链表构建器，每次追加都是O(1)：先倒序压入头部，finish时一次性反转。
+/// 适合需要把构建中的链表保存在结构体中、无法持有尾部引用的场景；
+/// 每个结点仍在push时单独分配，不做成块的批量分配。
+/// 在一个函数内从迭代器构建时直接collect更快，FromIterator在尾部一次接入全部结点，不需要反转
+/// # 例子
+/// ```
+/// use link::*;
+/// let mut b = LinkBuilder::new();
+/// for i in 0..3 {
+///     b.push(i);
+/// }
+/// assert_eq!(b.len(), 3);
+/// assert_eq!(format!("{:?}", b.finish()), "[0, 1, 2]");
+/// ```
+pub struct LinkBuilder<T> {
+    rev: Link<T>,
+    len: usize,
+}
+impl<T> LinkBuilder<T> {
+    /// 创建空的构建器
+    pub fn new() -> Self {
+        LinkBuilder {rev: Link::new(), len: 0}
+    }
+    /// 在末尾追加元素
+    pub fn push(&mut self, val: T) {
+        self.rev.push(val);
+        self.len += 1;
+    }
+    /// 已追加的元素个数
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    /// 判断是否还没有追加元素
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    /// 完成构建，得到按追加顺序排列的链表
+    pub fn finish(mut self) -> Link<T> {
+        self.rev.reverse();
+        self.rev
+    }
+}
+impl<T> Default for LinkBuilder<T> {
+    fn default() -> Self {
+        LinkBuilder::new()
+    }
+}
+impl<T> Extend<T> for LinkBuilder<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for v in iter {
+            self.push(v);
+        }
+    }
+}
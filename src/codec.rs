@@ -0,0 +1,147 @@
+//! 基于变长整数的紧凑二进制编码
+use crate::{Link, LinkBuilder};
+use core::convert::TryFrom;
+use std::io::{self, Read, Write};
+
+/// 元素的编解码器，可以为自定义的元素类型或编码方式实现
+pub trait Codec<T> {
+    /// 把元素写入writer
+    fn encode<W: Write>(&self, value: &T, writer: &mut W) -> io::Result<()>;
+    /// 从reader读出一个元素
+    fn decode<R: Read>(&self, reader: &mut R) -> io::Result<T>;
+}
+/// 变长整数编解码器，每字节保存7位，有符号整数先做zigzag变换，
+/// 绝对值小的整数只占一个字节
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Varint;
+
+fn invalid(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+/// 写入一个变长整数
+pub fn write_varint<W: Write>(writer: &mut W, mut v: u64) -> io::Result<()> {
+    let mut buf = [0u8; 10];
+    let mut n = 0;
+    while v >= 0x80 {
+        buf[n] = v as u8 | 0x80;
+        v >>= 7;
+        n += 1;
+    }
+    buf[n] = v as u8;
+    writer.write_all(&buf[..=n])
+}
+/// 读出一个变长整数，超过64位时返回InvalidData错误
+pub fn read_varint<R: Read>(reader: &mut R) -> io::Result<u64> {
+    let mut v = 0u64;
+    for shift in (0..64).step_by(7) {
+        let mut byte = [0u8];
+        reader.read_exact(&mut byte)?;
+        let low = u64::from(byte[0] & 0x7f);
+        if shift == 63 && low > 1 {
+            break;
+        }
+        v |= low << shift;
+        if byte[0] & 0x80 == 0 {
+            return Ok(v);
+        }
+    }
+    Err(invalid("varint overflows u64"))
+}
+macro_rules! varint_unsigned {
+    ($($t:ty)*) => {$(
+        impl Codec<$t> for Varint {
+            fn encode<W: Write>(&self, value: &$t, writer: &mut W) -> io::Result<()> {
+                write_varint(writer, *value as u64)
+            }
+            fn decode<R: Read>(&self, reader: &mut R) -> io::Result<$t> {
+                <$t>::try_from(read_varint(reader)?).map_err(|_| invalid("varint out of range"))
+            }
+        }
+    )*};
+}
+macro_rules! varint_signed {
+    ($($t:ty)*) => {$(
+        impl Codec<$t> for Varint {
+            fn encode<W: Write>(&self, value: &$t, writer: &mut W) -> io::Result<()> {
+                let v = *value as i64;
+                write_varint(writer, ((v << 1) ^ (v >> 63)) as u64)
+            }
+            fn decode<R: Read>(&self, reader: &mut R) -> io::Result<$t> {
+                let v = read_varint(reader)?;
+                let v = (v >> 1) as i64 ^ -((v & 1) as i64);
+                <$t>::try_from(v).map_err(|_| invalid("varint out of range"))
+            }
+        }
+    )*};
+}
+varint_unsigned!(u8 u16 u32 u64 usize);
+varint_signed!(i8 i16 i32 i64 isize);
+impl Codec<bool> for Varint {
+    fn encode<W: Write>(&self, value: &bool, writer: &mut W) -> io::Result<()> {
+        writer.write_all(&[*value as u8])
+    }
+    fn decode<R: Read>(&self, reader: &mut R) -> io::Result<bool> {
+        match <Varint as Codec<u8>>::decode(self, reader)? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(invalid("invalid bool")),
+        }
+    }
+}
+/// 字符串编码为变长的字节数加UTF-8内容
+impl Codec<String> for Varint {
+    fn encode<W: Write>(&self, value: &String, writer: &mut W) -> io::Result<()> {
+        write_varint(writer, value.len() as u64)?;
+        writer.write_all(value.as_bytes())
+    }
+    fn decode<R: Read>(&self, reader: &mut R) -> io::Result<String> {
+        let len = read_varint(reader)?;
+        let mut buf = Vec::new();
+        reader.take(len).read_to_end(&mut buf)?;
+        if buf.len() as u64 != len {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        String::from_utf8(buf).map_err(|_| invalid("invalid utf-8"))
+    }
+}
+impl<T> Link<T> {
+    /// 用Varint编码写入链表：变长的元素个数后接各元素的编码
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let l: Link<i32> = link![1, -2, 300, 0];
+    /// let mut buf = Vec::new();
+    /// l.encode_compact(&mut buf).unwrap();
+    /// assert_eq!(buf.len(), 1 + 1 + 1 + 2 + 1);
+    /// let d: Link<i32> = Link::decode_compact(&mut buf.as_slice()).unwrap();
+    /// assert_eq!(format!("{:?}", d), "[1, -2, 300, 0]");
+    /// ```
+    pub fn encode_compact<W: Write>(&self, writer: &mut W) -> io::Result<()>
+    where
+        Varint: Codec<T> {
+        self.encode_compact_with(writer, &Varint)
+    }
+    /// 用Varint编码读出链表
+    pub fn decode_compact<R: Read>(reader: &mut R) -> io::Result<Self>
+    where
+        Varint: Codec<T> {
+        Self::decode_compact_with(reader, &Varint)
+    }
+    /// 用指定的编解码器写入链表
+    pub fn encode_compact_with<W: Write, C: Codec<T>>(&self, writer: &mut W, codec: &C) -> io::Result<()> {
+        write_varint(writer, self.len() as u64)?;
+        for v in self {
+            codec.encode(v, writer)?;
+        }
+        Ok(())
+    }
+    /// 用指定的编解码器读出链表
+    pub fn decode_compact_with<R: Read, C: Codec<T>>(reader: &mut R, codec: &C) -> io::Result<Self> {
+        let len = read_varint(reader)?;
+        let mut builder = LinkBuilder::new();
+        for _ in 0..len {
+            builder.push(codec.decode(reader)?);
+        }
+        Ok(builder.finish())
+    }
+}
@@ -0,0 +1,487 @@
+//! 可以在多个线程之间共享的并发链表和队列
+use crate::Link;
+use parking_lot::{ArcMutexGuard, Mutex, RawMutex, RwLock};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, OnceLock};
+
+/// 链表中的一个位置，为None时表示链表在此结束
+type Cell<T> = Arc<Mutex<Option<Entry<T>>>>;
+type Guard<T> = ArcMutexGuard<RawMutex, Option<Entry<T>>>;
+struct Entry<T> {
+    value: T,
+    next: Cell<T>,
+}
+/// 有序并发链表，元素不重复，insert/remove/contains可以在多个线程中同时调用
+///
+/// 基于锁实现，不是无锁链表：每个位置有独立的互斥锁，遍历时采用交替加锁（先锁住后继再释放当前位置），
+/// 不同线程对链表不同部分的修改互不阻塞，但经过同一位置的操作会相互等待
+/// # 例子
+/// ```
+/// use std::sync::Arc;
+/// use std::thread;
+/// use link::concurrent::ConcurrentList;
+/// let list = Arc::new(ConcurrentList::new());
+/// let handles: Vec<_> = (0..4).map(|t| {
+///     let list = Arc::clone(&list);
+///     thread::spawn(move || {
+///         for i in 0..500 {
+///             list.insert(i * 4 + t);
+///         }
+///         for i in 0..500 {
+///             if (i * 4 + t) % 2 == 1 {
+///                 assert!(list.remove(&(i * 4 + t)));
+///             }
+///         }
+///     })
+/// }).collect();
+/// for h in handles {
+///     h.join().unwrap();
+/// }
+/// assert_eq!(list.len(), 1000);
+/// assert_eq!(list.to_vec(), (0..2000).step_by(2).collect::<Vec<_>>());
+/// assert!(list.contains(&10));
+/// assert!(!list.contains(&11));
+/// ```
+pub struct ConcurrentList<T> {
+    head: Cell<T>,
+}
+impl<T: Ord> ConcurrentList<T> {
+    /// 创建空链表
+    pub fn new() -> Self {
+        ConcurrentList {head: Arc::new(Mutex::new(None))}
+    }
+    /// 交替加锁前进，返回第一个不小于value的位置的锁
+    fn seek(&self, value: &T) -> Guard<T> {
+        let mut guard = self.head.lock_arc();
+        loop {
+            let next = match &*guard {
+                Some(e) if e.value < *value => Arc::clone(&e.next),
+                _ => return guard,
+            };
+            guard = next.lock_arc();
+        }
+    }
+    /// 按顺序插入元素，元素已存在时返回false
+    pub fn insert(&self, value: T) -> bool {
+        let mut guard = self.seek(&value);
+        if matches!(&*guard, Some(e) if e.value == value) {
+            return false;
+        }
+        let rest = guard.take();
+        *guard = Some(Entry {value, next: Arc::new(Mutex::new(rest))});
+        true
+    }
+    /// 删除元素，元素不存在时返回false
+    pub fn remove(&self, value: &T) -> bool {
+        let mut guard = self.seek(value);
+        let next = match &*guard {
+            Some(e) if e.value == *value => Arc::clone(&e.next),
+            _ => return false,
+        };
+        let rest = next.lock().take();
+        *guard = rest;
+        true
+    }
+    /// 判断元素是否存在
+    pub fn contains(&self, value: &T) -> bool {
+        matches!(&*self.seek(value), Some(e) if e.value == *value)
+    }
+}
+impl<T> ConcurrentList<T> {
+    /// 按顺序访问每个元素，访问期间持有当前位置的锁
+    fn for_each<F: FnMut(&T)>(&self, mut f: F) {
+        let mut guard = self.head.lock_arc();
+        loop {
+            let next = match &*guard {
+                Some(e) => {
+                    f(&e.value);
+                    Arc::clone(&e.next)
+                },
+                None => return,
+            };
+            guard = next.lock_arc();
+        }
+    }
+    /// 获取链表长度，与其他线程的修改同时进行时结果只是近似值
+    pub fn len(&self) -> usize {
+        let mut len = 0;
+        self.for_each(|_| len += 1);
+        len
+    }
+    /// 判断链表是否为空
+    pub fn is_empty(&self) -> bool {
+        self.head.lock().is_none()
+    }
+    /// 按顺序复制全部元素
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone {
+        let mut v = Vec::new();
+        self.for_each(|x| v.push(x.clone()));
+        v
+    }
+}
+impl<T: Ord> Default for ConcurrentList<T> {
+    fn default() -> Self {
+        ConcurrentList::new()
+    }
+}
+/// 逐个释放结点，避免长链表递归析构导致栈溢出
+impl<T> Drop for ConcurrentList<T> {
+    fn drop(&mut self) {
+        let mut cur = self.head.lock().take();
+        while let Some(e) = cur {
+            cur = match Arc::try_unwrap(e.next) {
+                Ok(m) => m.into_inner(),
+                Err(_) => None,
+            };
+        }
+    }
+}
+/// 每个块的槽位数
+const BLOCK: usize = 32;
+/// SegQueue的块，write为已被生产者占用的槽位数，read为已被消费者占用的槽位数
+struct Block<T> {
+    slots: [Mutex<Option<T>>; BLOCK],
+    write: AtomicUsize,
+    read: AtomicUsize,
+    next: OnceLock<Arc<Block<T>>>,
+}
+impl<T> Block<T> {
+    fn new() -> Arc<Self> {
+        Arc::new(Block {
+            slots: core::array::from_fn(|_| Mutex::new(None)),
+            write: AtomicUsize::new(0),
+            read: AtomicUsize::new(0),
+            next: OnceLock::new(),
+        })
+    }
+}
+/// 分段的无界多生产者多消费者队列，每个结点是一块定长的槽位，
+/// 生产者和消费者通过原子索引认领槽位，每BLOCK个元素才分配一次，
+/// 同一块内的不同槽位互不阻塞。
+///
+/// 基于锁实现，不是无锁队列：队首和队尾的块指针由读写锁保护，每个槽位有独立的互斥锁，
+/// 消费者认领了生产者尚未写完的槽位时自旋等待
+/// # 例子
+/// ```
+/// use std::sync::Arc;
+/// use std::thread;
+/// use link::concurrent::SegQueue;
+/// let q = Arc::new(SegQueue::new());
+/// let producers: Vec<_> = (0..4).map(|t| {
+///     let q = Arc::clone(&q);
+///     thread::spawn(move || for i in 0..1000 { q.push(t * 1000 + i) })
+/// }).collect();
+/// for p in producers {
+///     p.join().unwrap();
+/// }
+/// let mut got = Vec::new();
+/// while let Some(v) = q.pop() {
+///     got.push(v);
+/// }
+/// got.sort();
+/// assert_eq!(got, (0..4000).collect::<Vec<_>>());
+/// assert!(q.is_empty());
+/// ```
+pub struct SegQueue<T> {
+    head: RwLock<Arc<Block<T>>>,
+    tail: RwLock<Arc<Block<T>>>,
+}
+impl<T> SegQueue<T> {
+    /// 创建空队列
+    pub fn new() -> Self {
+        let block = Block::new();
+        SegQueue {head: RwLock::new(Arc::clone(&block)), tail: RwLock::new(block)}
+    }
+    /// 把位置从已用完的块移到它的下一块
+    fn advance(pos: &RwLock<Arc<Block<T>>>, block: &Arc<Block<T>>, next: &Arc<Block<T>>) {
+        let mut pos = pos.write();
+        if Arc::ptr_eq(&pos, block) {
+            *pos = Arc::clone(next);
+        }
+    }
+    /// 在队尾追加元素
+    pub fn push(&self, value: T) {
+        loop {
+            let block = Arc::clone(&self.tail.read());
+            let i = block.write.fetch_add(1, Ordering::AcqRel);
+            if i < BLOCK {
+                *block.slots[i].lock() = Some(value);
+                return;
+            }
+            let next = block.next.get_or_init(Block::new);
+            Self::advance(&self.tail, &block, next);
+        }
+    }
+    /// 弹出队首元素，队列为空时返回None
+    pub fn pop(&self) -> Option<T> {
+        loop {
+            let block = Arc::clone(&self.head.read());
+            let r = block.read.load(Ordering::Acquire);
+            if r == BLOCK {
+                Self::advance(&self.head, &block, block.next.get()?);
+                continue;
+            }
+            if r >= block.write.load(Ordering::Acquire).min(BLOCK) {
+                return None;
+            }
+            if block.read.compare_exchange(r, r + 1, Ordering::AcqRel, Ordering::Acquire).is_err() {
+                continue;
+            }
+            // 生产者已认领该槽位，等待它写入
+            loop {
+                if let Some(v) = block.slots[r].lock().take() {
+                    return Some(v);
+                }
+                std::thread::yield_now();
+            }
+        }
+    }
+    /// 判断队列是否为空，与其他线程的修改同时进行时结果只是近似值
+    pub fn is_empty(&self) -> bool {
+        let mut block = Arc::clone(&self.head.read());
+        loop {
+            let r = block.read.load(Ordering::Acquire);
+            if r < BLOCK {
+                return r >= block.write.load(Ordering::Acquire).min(BLOCK);
+            }
+            block = match block.next.get() {
+                Some(next) => Arc::clone(next),
+                None => return true,
+            };
+        }
+    }
+}
+impl<T> Default for SegQueue<T> {
+    fn default() -> Self {
+        SegQueue::new()
+    }
+}
+/// 逐块释放，避免长队列递归析构导致栈溢出
+impl<T> Drop for SegQueue<T> {
+    fn drop(&mut self) {
+        let mut cur = Some(Arc::clone(&self.head.read()));
+        *self.head.get_mut() = Arc::clone(&self.tail.read());
+        while let Some(block) = cur {
+            cur = match Arc::try_unwrap(block) {
+                Ok(mut b) => b.next.take(),
+                Err(_) => None,
+            };
+        }
+    }
+}
+/// Worker和Stealer共享的任务链表，len与链表同步维护，避免持锁时遍历计数
+struct Local<T> {
+    link: Link<T>,
+    len: usize,
+}
+/// 工作窃取中属于一个工作线程的任务链表，所有者在头部压入和弹出任务（后进先出），
+/// 其他线程通过Stealer一次窃取尾部较早的一半任务。
+///
+/// 窃取只在锁内找到中点并断开链接，被窃取的结点整体转移，不复制也不重新分配元素
+/// # 例子
+/// ```
+/// use std::thread;
+/// use link::concurrent::Worker;
+/// let w = Worker::new();
+/// for job in 0..10 {
+///     w.push(job);
+/// }
+/// let s = w.stealer();
+/// let thief = thread::spawn(move || s.steal_half());
+/// let stolen = thief.join().unwrap();
+/// // 窃取最早放入的一半任务，所有者继续处理最新的任务
+/// assert_eq!(format!("{:?}", stolen), "[4, 3, 2, 1, 0]");
+/// assert_eq!(w.len(), 5);
+/// assert_eq!(w.pop(), Some(9));
+/// ```
+pub struct Worker<T> {
+    local: Arc<Mutex<Local<T>>>,
+}
+impl<T> Worker<T> {
+    /// 创建空的任务链表
+    pub fn new() -> Self {
+        Worker {local: Arc::new(Mutex::new(Local {link: Link::new(), len: 0}))}
+    }
+    /// 生成可以发送给其他线程的窃取端
+    pub fn stealer(&self) -> Stealer<T> {
+        Stealer {local: Arc::clone(&self.local)}
+    }
+    /// 在头部压入任务
+    pub fn push(&self, value: T) {
+        let mut l = self.local.lock();
+        l.link.push(value);
+        l.len += 1;
+    }
+    /// 从头部弹出最新的任务
+    pub fn pop(&self) -> Option<T> {
+        let mut l = self.local.lock();
+        let v = l.link.pop()?;
+        l.len -= 1;
+        Some(v)
+    }
+    /// 任务个数
+    pub fn len(&self) -> usize {
+        self.local.lock().len
+    }
+    /// 判断是否没有任务
+    pub fn is_empty(&self) -> bool {
+        self.local.lock().len == 0
+    }
+    /// 从其他工作线程窃取一半任务放到自己的头部，返回窃取的个数，
+    /// 两次加锁互不嵌套，不会与反向窃取形成死锁
+    /// # 例子
+    /// ```
+    /// use link::concurrent::Worker;
+    /// let busy = Worker::new();
+    /// for job in 0..5 {
+    ///     busy.push(job);
+    /// }
+    /// let idle = Worker::new();
+    /// assert_eq!(idle.steal_from(&busy.stealer()), 3);
+    /// assert_eq!((busy.len(), idle.len()), (2, 3));
+    /// assert_eq!(idle.pop(), Some(2));
+    /// ```
+    pub fn steal_from(&self, victim: &Stealer<T>) -> usize {
+        let mut stolen = victim.steal_half();
+        let n = stolen.len();
+        if n > 0 {
+            let mut l = self.local.lock();
+            stolen.concat(l.link.take());
+            l.link = stolen;
+            l.len += n;
+        }
+        n
+    }
+}
+impl<T> Default for Worker<T> {
+    fn default() -> Self {
+        Worker::new()
+    }
+}
+/// Worker的窃取端，可以克隆并发送给任意多个线程
+pub struct Stealer<T> {
+    local: Arc<Mutex<Local<T>>>,
+}
+impl<T> Stealer<T> {
+    /// 窃取尾部较早的一半任务（奇数个时向上取整），按原顺序返回，
+    /// 持锁期间只遍历到中点，没有任务时返回空链表
+    pub fn steal_half(&self) -> Link<T> {
+        let mut l = self.local.lock();
+        let keep = l.len / 2;
+        let stolen = match keep {
+            0 => l.link.take(),
+            k => l.link.split_off(k - 1),
+        };
+        l.len = keep;
+        stolen
+    }
+    /// 对应Worker中的任务个数，与其他线程的修改同时进行时结果只是近似值
+    pub fn len(&self) -> usize {
+        self.local.lock().len
+    }
+    /// 判断对应Worker是否没有任务
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+impl<T> Clone for Stealer<T> {
+    fn clone(&self) -> Self {
+        Stealer {local: Arc::clone(&self.local)}
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::{ConcurrentList, SegQueue};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use std::collections::BTreeSet;
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::thread;
+
+    #[test]
+    fn seg_queue_mpmc() {
+        const PRODUCERS: usize = 4;
+        const CONSUMERS: usize = 4;
+        const PER: usize = 20_000;
+        let q = Arc::new(SegQueue::new());
+        let popped = Arc::new(AtomicUsize::new(0));
+        let producers: Vec<_> = (0..PRODUCERS).map(|t| {
+            let q = Arc::clone(&q);
+            thread::spawn(move || for i in 0..PER { q.push((t, i)) })
+        }).collect();
+        // 消费者与生产者同时运行，直到全部元素都被取出
+        let consumers: Vec<_> = (0..CONSUMERS).map(|_| {
+            let (q, popped) = (Arc::clone(&q), Arc::clone(&popped));
+            thread::spawn(move || {
+                let mut got = Vec::new();
+                let mut last = [None; PRODUCERS];
+                while popped.load(Ordering::Acquire) < PRODUCERS * PER {
+                    if let Some((t, i)) = q.pop() {
+                        popped.fetch_add(1, Ordering::AcqRel);
+                        // 同一个生产者的元素按入队顺序被取出
+                        assert!(last[t].is_none_or(|l| l < i), "out of order: {:?} then {}", last[t], i);
+                        last[t] = Some(i);
+                        got.push((t, i));
+                    }
+                }
+                got
+            })
+        }).collect();
+        for p in producers {
+            p.join().unwrap();
+        }
+        let mut all: Vec<_> = consumers.into_iter().flat_map(|c| c.join().unwrap()).collect();
+        all.sort();
+        let expected: Vec<_> = (0..PRODUCERS).flat_map(|t| (0..PER).map(move |i| (t, i))).collect();
+        assert_eq!(all, expected);
+        assert!(q.is_empty());
+        assert_eq!(q.pop(), None);
+    }
+    #[test]
+    fn mixed_insert_remove_contains() {
+        const THREADS: usize = 4;
+        const KEYS: usize = 256;
+        let list = Arc::new(ConcurrentList::new());
+        let done = Arc::new(AtomicBool::new(false));
+        // 读线程在修改进行中反复遍历，任何时刻看到的元素都必须严格递增
+        let reader = {
+            let (list, done) = (Arc::clone(&list), Arc::clone(&done));
+            thread::spawn(move || {
+                while !done.load(Ordering::Acquire) {
+                    let v = list.to_vec();
+                    assert!(v.windows(2).all(|w| w[0] < w[1]), "unsorted or duplicated: {:?}", v);
+                }
+            })
+        };
+        // 每个写线程只修改 key % THREADS == t 的元素，用自己的模型核对每次操作的结果
+        let writers: Vec<_> = (0..THREADS).map(|t| {
+            let list = Arc::clone(&list);
+            thread::spawn(move || {
+                let mut rng = StdRng::seed_from_u64(t as u64);
+                let mut model = BTreeSet::new();
+                for _ in 0..20_000 {
+                    let key = rng.gen_range(0..KEYS / THREADS) * THREADS + t;
+                    match rng.gen_range(0..3) {
+                        0 => assert_eq!(list.insert(key), model.insert(key)),
+                        1 => assert_eq!(list.remove(&key), model.remove(&key)),
+                        _ => assert_eq!(list.contains(&key), model.contains(&key)),
+                    }
+                    // 其他线程的元素只做查询，检验并发修改下遍历不会出错
+                    list.contains(&rng.gen_range(0..KEYS));
+                }
+                model
+            })
+        }).collect();
+        let mut expected = BTreeSet::new();
+        for w in writers {
+            expected.extend(w.join().unwrap());
+        }
+        done.store(true, Ordering::Release);
+        reader.join().unwrap();
+        assert_eq!(list.to_vec(), expected.into_iter().collect::<Vec<_>>());
+        assert_eq!(list.len(), list.to_vec().len());
+    }
+}
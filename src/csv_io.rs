@@ -0,0 +1,56 @@
+//! 与csv库的集成，把表格的行读入链表
+use crate::{Link, Node};
+use csv::{Reader, StringRecord};
+use std::io;
+use std::str::FromStr;
+
+impl Link<String> {
+    /// 把一行记录的各列转化为字符串链表
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let record = csv::StringRecord::from(vec!["a", "b", "c"]);
+    /// let l = Link::from_csv_record(&record);
+    /// assert_eq!(format!("{:?}", l), r#"["a", "b", "c"]"#);
+    /// ```
+    pub fn from_csv_record(record: &StringRecord) -> Self {
+        record.iter().map(String::from).collect()
+    }
+}
+impl Link<Link<String>> {
+    /// 读取所有记录，每行一个字符串链表
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let data = "name,age\nTom,3\nJerry,2\n";
+    /// let mut reader = csv::Reader::from_reader(data.as_bytes());
+    /// let rows: Link<Link<String>> = Link::from_csv_reader(&mut reader).unwrap();
+    /// assert_eq!(rows.len(), 2);
+    /// assert_eq!(rows[1][0], "Jerry");
+    /// ```
+    pub fn from_csv_reader<R: io::Read>(reader: &mut Reader<R>) -> Result<Self, csv::Error> {
+        reader.records()
+            .map(|r| r.map(|record| Link::from_csv_record(&record)))
+            .collect::<Result<Vec<_>, _>>()
+            .map(|rows| rows.into_iter().collect())
+    }
+}
+impl<T: FromStr> Link<T> {
+    /// 把一行记录的每一列解析为T，遇到第一个解析失败的列时返回错误
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let record = csv::StringRecord::from(vec!["1", "2", "3"]);
+    /// let l: Link<i32> = Link::parse_csv_record(&record).unwrap();
+    /// assert_eq!(format!("{:?}", l), "[1, 2, 3]");
+    /// ```
+    pub fn parse_csv_record(record: &StringRecord) -> Result<Self, T::Err> {
+        let mut link = Link::new();
+        let mut node = &mut link;
+        for field in record.iter() {
+            *node = Node::new(field.parse()?, None).as_link();
+            node = &mut node.0.as_mut().unwrap().next;
+        }
+        Ok(link)
+    }
+}
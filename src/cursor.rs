@@ -0,0 +1,354 @@
+//! 可以在遍历中修改链表的游标
+use crate::{Link, Node};
+use core::fmt;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// 下一个游标的编号，每个游标的编号都不相同
+static NEXT_CURSOR: AtomicUsize = AtomicUsize::new(0);
+/// 取得新游标的编号
+#[cfg(target_has_atomic = "ptr")]
+fn next_cursor() -> usize {
+    NEXT_CURSOR.fetch_add(1, Ordering::Relaxed)
+}
+/// 不支持原子读改写的目标（如thumbv6m）上分别读写，
+/// 在中断处理中同时创建游标时两个游标的编号可能相同
+#[cfg(not(target_has_atomic = "ptr"))]
+fn next_cursor() -> usize {
+    let id = NEXT_CURSOR.load(Ordering::Relaxed);
+    NEXT_CURSOR.store(id.wrapping_add(1), Ordering::Relaxed);
+    id
+}
+impl<T> Link<T> {
+    /// 生成指向第一个元素的可变游标
+    pub fn cursor_mut(&mut self) -> CursorMut<'_, T> {
+        let id = next_cursor();
+        CursorMut {
+            rest: self,
+            before: Link::new(),
+            index: 0,
+            generation: Generation {cursor: id, edits: 0},
+            edits: EditLog::new(),
+        }
+    }
+}
+/// 游标的修改代数，由游标编号和游标上结构性修改的次数组成。
+/// 游标存在期间独占链表，链表只能通过游标修改，因此代数相同就说明链表结构没有变化；
+/// 不同游标的代数总是不同的，游标之间链表可能已被其他方式修改
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Generation {
+    cursor: usize,
+    edits: usize,
+}
+/// 记录的代数已过期：链表在记录之后被修改过，或者代数来自另一个游标
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StaleHandle;
+impl fmt::Display for StaleHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("handle is stale: the list was modified after it was taken")
+    }
+}
+impl core::error::Error for StaleHandle {}
+/// EditLog最多保存的记录数
+const EDIT_LOG: usize = 16;
+/// 结构性修改位置的后缀最小值摘要，用于检查书签。
+///
+/// 记录(n, at)表示序号在上一个记录的序号之后、不超过n的修改起，之后全部修改的最小位置为at，
+/// 记录的序号和位置都严格递增。新的修改先弹出位置不小于它的记录再压入，均摊O(1)；
+/// 记录满时把最早的两个记录合并为一个，较早书签的检查因此偏于保守（可能把仍有效的书签判为失效），
+/// 占用的空间不随修改次数增长
+struct EditLog {
+    entries: [(usize, usize); EDIT_LOG],
+    len: usize,
+}
+impl EditLog {
+    fn new() -> Self {
+        EditLog {entries: [(0, 0); EDIT_LOG], len: 0}
+    }
+    /// 记录序号为n、位置为at的修改
+    fn push(&mut self, n: usize, at: usize) {
+        while self.len > 0 && self.entries[self.len - 1].1 >= at {
+            self.len -= 1;
+        }
+        if self.len == EDIT_LOG {
+            self.entries[1].1 = self.entries[0].1;
+            self.entries.copy_within(1.., 0);
+            self.len -= 1;
+        }
+        self.entries[self.len] = (n, at);
+        self.len += 1;
+    }
+    /// 序号不小于n的修改中的最小位置，没有这样的修改时返回None
+    fn min_since(&self, n: usize) -> Option<usize> {
+        self.entries[..self.len].iter().find(|&&(i, _)| i >= n).map(|&(_, at)| at)
+    }
+}
+/// 可变游标，指向链表中的一个元素或末尾之后的位置，可以向前后移动，
+/// 在当前位置插入、删除和分割都是O(1)，一次遍历中可以做任意多次修改。
+///
+/// 游标存在期间，当前位置之前的结点按逆序链接在游标内部，移动只修改链接；
+/// 游标析构时把它们接回链表，为O(index)。游标被mem::forget时这些元素会从链表中丢失
+/// # 例子
+/// ```
+/// use link::*;
+/// let mut l: Link<isize> = link![1, -2, 4, -3, 5];
+/// let mut c = l.cursor_mut();
+/// // 删除负数，并在每个偶数后面插入0
+/// while let Some(v) = c.current() {
+///     if *v < 0 {
+///         c.remove_current();
+///         continue;
+///     }
+///     if *v % 2 == 0 {
+///         c.insert_after(0);
+///         c.move_next();
+///     }
+///     c.move_next();
+/// }
+/// drop(c);
+/// assert_eq!(format!("{:?}", l), "[1, 4, 0, 5]");
+/// let mut c = l.cursor_mut();
+/// c.insert_before(0);
+/// c.move_next();
+/// let tail = c.split_after();
+/// drop(c);
+/// assert_eq!(format!("{:?}", l), "[0, 1, 4]");
+/// assert_eq!(format!("{:?}", tail), "[0, 5]");
+/// ```
+pub struct CursorMut<'a, T> {
+    // 链表中从当前元素开始的部分
+    rest: &'a mut Link<T>,
+    // 当前元素之前的部分，逆序保存，第一个结点是前一个元素
+    before: Link<T>,
+    index: usize,
+    generation: Generation,
+    // 结构性修改影响到的最小索引的摘要，用于检查书签
+    edits: EditLog,
+}
+/// 游标中一个位置的书签，由索引和记录时的修改代数组成，
+/// 之后的修改只要都发生在书签位置之后，书签就仍然有效
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Bookmark {
+    index: usize,
+    generation: Generation,
+}
+impl Bookmark {
+    /// 记录的索引
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+impl<'a, T> CursorMut<'a, T> {
+    /// 当前元素的索引，位于末尾时等于链表长度
+    pub fn index(&self) -> usize {
+        self.index
+    }
+    /// 当前的修改代数，插入、删除、分割和拼接都会使它改变，移动不会
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let mut l: Link<isize> = link![1, 2, 3];
+    /// let mut c = l.cursor_mut();
+    /// let g = c.generation();
+    /// c.move_next();
+    /// assert_eq!(c.check(g), Ok(()));
+    /// c.remove_current();
+    /// assert_eq!(c.check(g), Err(StaleHandle));
+    /// drop(c);
+    /// // 另一个游标不接受之前游标的代数
+    /// let c = l.cursor_mut();
+    /// assert_eq!(c.check(g), Err(StaleHandle));
+    /// ```
+    pub fn generation(&self) -> Generation {
+        self.generation
+    }
+    /// 检查代数是否仍然有效，即来自这个游标且之后没有结构性修改
+    pub fn check(&self, g: Generation) -> Result<(), StaleHandle> {
+        match g == self.generation {
+            true => Ok(()),
+            false => Err(StaleHandle),
+        }
+    }
+    /// 记录一次结构性修改，at为受影响的最小索引，之前的元素索引不变
+    fn edited(&mut self, at: usize) {
+        self.edits.push(self.generation.edits, at);
+        self.generation.edits += 1;
+    }
+    /// 记录当前位置的书签
+    pub fn bookmark(&self) -> Bookmark {
+        Bookmark {index: self.index, generation: self.generation}
+    }
+    /// 检查书签是否仍然有效：来自这个游标，且记录之后的修改都只影响书签之后的元素，
+    /// 书签的索引因此仍指向原来的元素（书签位于末尾时指向同一个位置）。
+    /// 游标只保存有限的修改摘要，记录之后修改次数很多时，较早的书签可能被保守地判为失效
+    pub fn validate(&self, b: Bookmark) -> Result<(), StaleHandle> {
+        if b.generation.cursor != self.generation.cursor {
+            return Err(StaleHandle);
+        }
+        match self.edits.min_since(b.generation.edits) {
+            Some(at) if at <= b.index => Err(StaleHandle),
+            _ => Ok(()),
+        }
+    }
+    /// 移动到书签记录的位置，O(移动距离)，书签失效时返回StaleHandle且不移动
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let mut l: Link<isize> = link![3, 10, 4, 20, 5];
+    /// let mut c = l.cursor_mut();
+    /// // 第一遍记录大于9的元素
+    /// let mut marks = Vec::new();
+    /// while let Some(v) = c.current() {
+    ///     if *v > 9 {
+    ///         marks.push(c.bookmark());
+    ///     }
+    ///     c.move_next();
+    /// }
+    /// // 第二遍从后往前修改，后面的修改不影响前面的书签
+    /// for m in marks.iter().rev() {
+    ///     c.seek(*m).unwrap();
+    ///     c.insert_after(0);
+    /// }
+    /// assert!(c.seek(marks[1]).is_err());
+    /// c.seek(marks[0]).unwrap();
+    /// c.remove_current();
+    /// drop(c);
+    /// assert_eq!(format!("{:?}", l), "[3, 0, 4, 20, 0, 5]");
+    /// ```
+    pub fn seek(&mut self, b: Bookmark) -> Result<(), StaleHandle> {
+        self.validate(b)?;
+        while self.index > b.index {
+            self.move_prev();
+        }
+        while self.index < b.index {
+            self.move_next();
+        }
+        Ok(())
+    }
+    /// 移动到下一个元素，已位于末尾时返回false且不移动
+    pub fn move_next(&mut self) -> bool {
+        let mut node = match self.rest.0.take() {
+            Some(node) => node,
+            None => return false,
+        };
+        self.rest.0 = node.next.0.take();
+        node.next = self.before.take();
+        self.before.0 = Some(node);
+        self.index += 1;
+        count_op!(STEPS);
+        true
+    }
+    /// 移动到上一个元素，已位于开头时返回false且不移动
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let mut l: Link<isize> = link![1, 2, 3];
+    /// let mut c = l.cursor_mut();
+    /// c.move_next();
+    /// c.move_next();
+    /// assert!(c.move_prev());
+    /// assert_eq!(c.current(), Some(&mut 2));
+    /// assert_eq!(c.peek_prev(), Some(&mut 1));
+    /// assert!(c.move_prev());
+    /// assert!(!c.move_prev());
+    /// ```
+    pub fn move_prev(&mut self) -> bool {
+        let mut node = match self.before.0.take() {
+            Some(node) => node,
+            None => return false,
+        };
+        self.before.0 = node.next.0.take();
+        node.next = self.rest.take();
+        self.rest.0 = Some(node);
+        self.index -= 1;
+        count_op!(STEPS);
+        true
+    }
+    /// 当前元素的可变引用，位于末尾时返回None
+    pub fn current(&mut self) -> Option<&mut T> {
+        self.rest.0.as_mut().map(|n| &mut n.value)
+    }
+    /// 下一个元素的可变引用
+    pub fn peek_next(&mut self) -> Option<&mut T> {
+        self.rest.0.as_mut()?.next.0.as_mut().map(|n| &mut n.value)
+    }
+    /// 上一个元素的可变引用
+    pub fn peek_prev(&mut self) -> Option<&mut T> {
+        self.before.0.as_mut().map(|n| &mut n.value)
+    }
+    /// 在当前元素之前插入，游标仍指向原来的元素
+    pub fn insert_before(&mut self, val: T) {
+        self.before = Node::new(val, self.before.0.take()).as_link();
+        self.edited(self.index);
+        self.index += 1;
+    }
+    /// 在当前元素之后插入，位于末尾时插入的元素成为当前元素
+    pub fn insert_after(&mut self, val: T) {
+        let at = match self.rest.0.is_some() {
+            true => self.index + 1,
+            false => self.index,
+        };
+        let slot = match self.rest.0.as_mut() {
+            Some(n) => &mut n.next,
+            None => &mut *self.rest,
+        };
+        *slot = Node::new(val, slot.0.take()).as_link();
+        self.edited(at);
+    }
+    /// 删除并返回当前元素，游标移到下一个元素，位于末尾时返回None
+    pub fn remove_current(&mut self) -> Option<T> {
+        let mut node = self.rest.0.take()?;
+        self.rest.0 = node.next.0.take();
+        self.edited(self.index);
+        let Node {value, ..} = *node;
+        Some(value)
+    }
+    /// 把当前元素之后的部分分割为新链表，位于末尾时返回空链表
+    pub fn split_after(&mut self) -> Link<T> {
+        self.edited(self.index + 1);
+        match self.rest.0.as_mut() {
+            Some(n) => n.next.take(),
+            None => Link::new(),
+        }
+    }
+    /// 把当前元素及之后的部分分割为新链表，游标位于分割后的末尾
+    pub fn split_before(&mut self) -> Link<T> {
+        self.edited(self.index);
+        self.rest.take()
+    }
+    /// 把other整体接到当前元素之后，只修改链接，位于末尾时other的第一个元素成为当前元素
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let mut l: Link<isize> = link![1, 4];
+    /// let mut c = l.cursor_mut();
+    /// c.splice_after(link![2, 3]);
+    /// c.move_next();
+    /// assert_eq!(c.current(), Some(&mut 2));
+    /// drop(c);
+    /// assert_eq!(format!("{:?}", l), "[1, 2, 3, 4]");
+    /// ```
+    pub fn splice_after(&mut self, mut other: Link<T>) {
+        let at = match self.rest.0.is_some() {
+            true => self.index + 1,
+            false => self.index,
+        };
+        let slot = match self.rest.0.as_mut() {
+            Some(n) => &mut n.next,
+            None => &mut *self.rest,
+        };
+        let rest = slot.take();
+        let mut tail = &mut other;
+        while tail.0.is_some() {
+            tail = &mut tail.0.as_mut().unwrap().next;
+        }
+        *tail = rest;
+        *slot = other;
+        self.edited(at);
+    }
+}
+/// 把当前位置之前的结点接回链表
+impl<T> Drop for CursorMut<'_, T> {
+    fn drop(&mut self) {
+        while self.move_prev() {}
+    }
+}
@@ -0,0 +1,17 @@
+//! 与defmt库的集成，用于嵌入式日志
+use crate::Link;
+use defmt::{Format, Formatter};
+
+/// defmt格式化表示，输出形如`[1, 2, 3]`
+impl<T: Format> Format for Link<T> {
+    fn format(&self, f: Formatter<'_>) {
+        defmt::write!(f, "[");
+        for (i, v) in self.iter().enumerate() {
+            if i > 0 {
+                defmt::write!(f, ", ");
+            }
+            defmt::write!(f, "{}", v);
+        }
+        defmt::write!(f, "]");
+    }
+}
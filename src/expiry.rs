@@ -0,0 +1,120 @@
+//! 按到达顺序过期的超时队列
+use crate::Link;
+use std::time::{Duration, Instant};
+
+/// 按到达顺序保存(到期时间, 元素)的超时队列，适合网络服务中的超时管理。
+///
+/// 内部用两个链表实现先进先出：front按从旧到新保存较早的元素，back按从新到旧保存新到达的元素，
+/// front取空时把back反转后移入front，push和pop均摊O(1)。
+/// 只有front为空时back才为空，因此最早的元素总在front的头部。
+/// expire_before只检查队首，到期时间应当随到达顺序单调不减（例如所有元素使用相同的TTL），
+/// 否则较晚到期的元素会挡住它后面已经到期的元素
+/// # 例子
+/// ```
+/// use std::time::{Duration, Instant};
+/// use link::expiry::TtlList;
+/// let start = Instant::now();
+/// let mut pending = TtlList::new();
+/// for id in 0..5 {
+///     pending.push(start + Duration::from_secs(id), id);
+/// }
+/// let expired = pending.expire_before(start + Duration::from_secs(3));
+/// assert_eq!(expired.iter().map(|(_, id)| *id).collect::<Vec<_>>(), [0, 1, 2]);
+/// assert_eq!(pending.len(), 2);
+/// assert_eq!(pending.next_expiry(), Some(start + Duration::from_secs(3)));
+/// ```
+pub struct TtlList<T> {
+    front: Link<(Instant, T)>,
+    back: Link<(Instant, T)>,
+    len: usize,
+}
+impl<T> TtlList<T> {
+    /// 创建空队列
+    pub fn new() -> Self {
+        TtlList {front: Link::new(), back: Link::new(), len: 0}
+    }
+    /// 获取元素个数，O(1)
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    /// 判断队列是否为空
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    /// front取空时把back移入front
+    fn refill(&mut self) {
+        if self.front.is_empty() {
+            self.back.reverse();
+            self.front = self.back.take();
+        }
+    }
+    /// 在队尾放入元素，expires_at为它的到期时间
+    pub fn push(&mut self, expires_at: Instant, val: T) {
+        match self.front.is_empty() {
+            true => self.front.push((expires_at, val)),
+            false => self.back.push((expires_at, val)),
+        }
+        self.len += 1;
+    }
+    /// 在队尾放入元素，从现在起经过ttl后到期
+    pub fn push_ttl(&mut self, ttl: Duration, val: T) {
+        self.push(Instant::now() + ttl, val);
+    }
+    /// 弹出最早到达的元素
+    pub fn pop(&mut self) -> Option<(Instant, T)> {
+        let v = self.front.pop()?;
+        self.refill();
+        self.len -= 1;
+        Some(v)
+    }
+    /// 最早到达的元素的到期时间
+    pub fn next_expiry(&self) -> Option<Instant> {
+        self.front.front().map(|(t, _)| *t)
+    }
+    /// 从队首摘下到期时间早于now的全部(到期时间, 元素)，按到达顺序返回，
+    /// 到期时间恰好等于now的元素留在队列中。摘下的结点直接链接到返回的链表，不重新分配，
+    /// 均摊O(k)，k为摘下的元素个数
+    pub fn expire_before(&mut self, now: Instant) -> Link<(Instant, T)> {
+        let mut expired = Link::new();
+        loop {
+            let mut n = 0;
+            let part = self.front.take_while_split(|(t, _)| {
+                let due = *t < now;
+                n += due as usize;
+                due
+            });
+            self.len -= n;
+            expired.concat(part);
+            if !self.front.is_empty() || self.back.is_empty() {
+                break;
+            }
+            self.refill();
+        }
+        expired
+    }
+    /// 按到达顺序遍历(到期时间, 元素)
+    /// # 例子
+    /// ```
+    /// use std::time::{Duration, Instant};
+    /// use link::expiry::TtlList;
+    /// let mut l = TtlList::new();
+    /// for s in ["a", "b", "c"] {
+    ///     l.push_ttl(Duration::from_secs(30), s);
+    /// }
+    /// assert_eq!(l.iter().map(|(_, s)| *s).collect::<String>(), "abc");
+    /// assert_eq!(l.pop().map(|(_, s)| s), Some("a"));
+    /// assert!(l.expire_before(Instant::now()).is_empty());
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (&Instant, &T)> {
+        self.front.iter().chain(self.back.iter().rev()).map(|(t, v)| (t, v))
+    }
+    /// 清空队列
+    pub fn clear(&mut self) {
+        *self = TtlList::new();
+    }
+}
+impl<T> Default for TtlList<T> {
+    fn default() -> Self {
+        TtlList::new()
+    }
+}
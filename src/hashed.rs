@@ -0,0 +1,191 @@
+//! 增量维护内容哈希的链表
+use crate::{Iter, Link};
+use core::fmt;
+use core::hash::{BuildHasher, BuildHasherDefault, Hash};
+use std::collections::hash_map::DefaultHasher;
+
+/// 多项式哈希的基数，必须是奇数才在模2^64下可逆
+const BASE: u64 = 0x0000_0100_0000_01b3;
+/// BASE在模2^64下的逆元，用牛顿迭代求得
+const BASE_INV: u64 = {
+    let mut inv = BASE;
+    let mut i = 0;
+    while i < 6 {
+        inv = inv.wrapping_mul(2u64.wrapping_sub(BASE.wrapping_mul(inv)));
+        i += 1;
+    }
+    inv
+};
+/// 内容哈希随每次修改增量更新的链表，content_hash()为O(1)
+///
+/// 哈希为Σ h(第i个元素)·BASE^i（模2^64），对元素顺序敏感，头部的增删为O(1)，
+/// 第i个位置的增删改在原有的O(i)遍历之外不增加额外的复杂度。
+/// 默认的哈希器不含随机种子，内容相同的链表得到相同的哈希
+/// # 例子
+/// ```
+/// use link::hashed::HashedLink;
+/// let mut a = HashedLink::new();
+/// a.push_back(1);
+/// a.push_back(2);
+/// let mut b = HashedLink::new();
+/// b.push(2);
+/// b.push(1);
+/// assert_eq!(a.content_hash(), b.content_hash());
+/// b.set(0, 3);
+/// assert_ne!(a.content_hash(), b.content_hash());
+/// b.set(0, 1);
+/// assert_eq!(a.content_hash(), b.content_hash());
+/// ```
+pub struct HashedLink<T, S = BuildHasherDefault<DefaultHasher>> {
+    link: Link<T>,
+    len: usize,
+    hash: u64,
+    // BASE^len
+    pow: u64,
+    hasher: S,
+}
+impl<T: Hash> HashedLink<T> {
+    /// 创建空链表
+    pub fn new() -> Self {
+        Self::with_hasher(Default::default())
+    }
+}
+impl<T: Hash, S: BuildHasher> HashedLink<T, S> {
+    /// 用指定的哈希器创建空链表
+    pub fn with_hasher(hasher: S) -> Self {
+        HashedLink {link: Link::new(), len: 0, hash: 0, pow: 1, hasher}
+    }
+    /// 用已有的链表创建，需要O(n)计算初始哈希
+    pub fn with_link(link: Link<T>, hasher: S) -> Self {
+        let mut l = Self::with_hasher(hasher);
+        for v in &link {
+            l.hash = l.hash.wrapping_add(l.pow.wrapping_mul(l.hasher.hash_one(v)));
+            l.pow = l.pow.wrapping_mul(BASE);
+            l.len += 1;
+        }
+        l.link = link;
+        l
+    }
+    /// 内容哈希，O(1)
+    pub fn content_hash(&self) -> u64 {
+        self.hash
+    }
+    /// 获取链表长度，O(1)
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    /// 判断链表是否为空
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    /// 前i个元素的哈希和BASE^i
+    fn prefix(&self, i: usize) -> (u64, u64) {
+        self.link.iter().take(i).fold((0, 1), |(h, p), v| {
+            (h.wrapping_add(p.wrapping_mul(self.hasher.hash_one(v))), p.wrapping_mul(BASE))
+        })
+    }
+    /// 在链表的头部压入一个元素，O(1)
+    pub fn push(&mut self, val: T) {
+        self.hash = self.hasher.hash_one(&val).wrapping_add(self.hash.wrapping_mul(BASE));
+        self.pow = self.pow.wrapping_mul(BASE);
+        self.len += 1;
+        self.link.push(val);
+    }
+    /// 在链表的尾部追加元素
+    pub fn push_back(&mut self, val: T) {
+        self.hash = self.hash.wrapping_add(self.pow.wrapping_mul(self.hasher.hash_one(&val)));
+        self.pow = self.pow.wrapping_mul(BASE);
+        self.len += 1;
+        self.link.push_back(val);
+    }
+    /// 在指定位置插入元素，当插入失败时返回None
+    pub fn insert(&mut self, i: usize, val: T) -> Option<&T> {
+        if i > self.len {
+            return None;
+        }
+        let (p, pw) = self.prefix(i);
+        let h = self.hasher.hash_one(&val);
+        let suffix = self.hash.wrapping_sub(p);
+        self.hash = p.wrapping_add(pw.wrapping_mul(h)).wrapping_add(suffix.wrapping_mul(BASE));
+        self.pow = self.pow.wrapping_mul(BASE);
+        self.len += 1;
+        self.link.insert(i, val)
+    }
+    /// 弹出第一个元素，O(1)，当链表为空时返回None
+    pub fn pop(&mut self) -> Option<T> {
+        let v = self.link.pop()?;
+        self.hash = self.hash.wrapping_sub(self.hasher.hash_one(&v)).wrapping_mul(BASE_INV);
+        self.pow = self.pow.wrapping_mul(BASE_INV);
+        self.len -= 1;
+        Some(v)
+    }
+    /// 弹出最后一个元素，当链表为空时返回None
+    pub fn pop_back(&mut self) -> Option<T> {
+        let v = self.link.pop_back()?;
+        self.pow = self.pow.wrapping_mul(BASE_INV);
+        self.hash = self.hash.wrapping_sub(self.pow.wrapping_mul(self.hasher.hash_one(&v)));
+        self.len -= 1;
+        Some(v)
+    }
+    /// 在指定位置删除元素，返回被删元素，当删除失败时返回None
+    pub fn delete(&mut self, i: usize) -> Option<T> {
+        if i >= self.len {
+            return None;
+        }
+        let (p, pw) = self.prefix(i);
+        let v = self.link.delete(i)?;
+        let suffix = self.hash.wrapping_sub(p).wrapping_sub(pw.wrapping_mul(self.hasher.hash_one(&v)));
+        self.hash = p.wrapping_add(suffix.wrapping_mul(BASE_INV));
+        self.pow = self.pow.wrapping_mul(BASE_INV);
+        self.len -= 1;
+        Some(v)
+    }
+    /// 替换第i个元素，返回原来的元素，索引超出范围时返回None
+    pub fn set(&mut self, i: usize, val: T) -> Option<T> {
+        if i >= self.len {
+            return None;
+        }
+        let (_, pw) = self.prefix(i);
+        let h = self.hasher.hash_one(&val);
+        let old = core::mem::replace(&mut self.link.get_mut(i)?.value, val);
+        let diff = h.wrapping_sub(self.hasher.hash_one(&old));
+        self.hash = self.hash.wrapping_add(pw.wrapping_mul(diff));
+        Some(old)
+    }
+    /// 清空链表
+    pub fn clear(&mut self) {
+        self.link = Link::new();
+        self.len = 0;
+        self.hash = 0;
+        self.pow = 1;
+    }
+}
+impl<T, S> HashedLink<T, S> {
+    /// 生成不可变迭代器，不提供可变访问以免哈希失效
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.link.iter()
+    }
+    /// 查看内部链表
+    pub fn as_link(&self) -> &Link<T> {
+        &self.link
+    }
+    /// 取出内部链表
+    pub fn into_inner(self) -> Link<T> {
+        self.link
+    }
+}
+impl<T: Hash> Default for HashedLink<T> {
+    fn default() -> Self {
+        HashedLink::new()
+    }
+}
+impl<T: Hash> From<Link<T>> for HashedLink<T> {
+    fn from(link: Link<T>) -> Self {
+        HashedLink::with_link(link, Default::default())
+    }
+}
+impl<T: fmt::Debug, S> fmt::Debug for HashedLink<T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.link.fmt(f)
+    }
+}
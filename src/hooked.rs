@@ -0,0 +1,128 @@
+//! 删除元素时调用回调的链表
+use crate::{Iter, Link};
+use core::fmt;
+
+/// 删除元素时调用回调的链表，pop/pop_back/delete/retain/clear以及析构时
+/// 每个被移出链表的元素都会先交给回调处理
+/// # 例子
+/// ```
+/// use link::*;
+/// let mut removed = Vec::new();
+/// {
+///     let mut l = HookedLink::new(|v: &mut isize| removed.push(*v));
+///     l.push_back(1);
+///     l.push_back(2);
+///     l.push_back(3);
+///     l.push_back(4);
+///     assert_eq!(l.pop(), Some(1));
+///     l.retain(|v| v % 2 == 0);
+/// }
+/// assert_eq!(removed, vec![1, 3, 2, 4]);
+/// ```
+pub struct HookedLink<T, F>
+where
+    F: FnMut(&mut T) {
+    link: Link<T>,
+    on_remove: F,
+}
+impl<T, F> HookedLink<T, F>
+where
+    F: FnMut(&mut T) {
+    /// 用回调创建空链表
+    pub fn new(on_remove: F) -> Self {
+        Self::with_link(Link::new(), on_remove)
+    }
+    /// 用已有的链表和回调创建
+    pub fn with_link(link: Link<T>, on_remove: F) -> Self {
+        HookedLink {link, on_remove}
+    }
+    /// 对被移出的元素调用回调
+    fn removed(&mut self, v: Option<T>) -> Option<T> {
+        let mut v = v?;
+        (self.on_remove)(&mut v);
+        Some(v)
+    }
+    /// 在链表的头部压入一个元素
+    pub fn push(&mut self, val: T) {
+        self.link.push(val);
+    }
+    /// 在链表的尾部追加元素
+    pub fn push_back(&mut self, val: T) {
+        self.link.push_back(val);
+    }
+    /// 在指定位置插入元素，当插入失败时返回None
+    pub fn insert(&mut self, i: usize, val: T) -> Option<&T> {
+        self.link.insert(i, val)
+    }
+    /// 弹出第一个元素，当链表为空时返回None
+    pub fn pop(&mut self) -> Option<T> {
+        let v = self.link.pop();
+        self.removed(v)
+    }
+    /// 弹出最后一个元素，当链表为空时返回None
+    pub fn pop_back(&mut self) -> Option<T> {
+        let v = self.link.pop_back();
+        self.removed(v)
+    }
+    /// 在指定位置删除元素，返回被删元素，当删除失败时返回None
+    pub fn delete(&mut self, i: usize) -> Option<T> {
+        let v = self.link.delete(i);
+        self.removed(v)
+    }
+    /// 只保留满足条件的元素，其余元素交给回调后丢弃
+    pub fn retain<P>(&mut self, mut pred: P)
+    where
+        P: FnMut(&T) -> bool {
+        let on_remove = &mut self.on_remove;
+        let mut node = &mut self.link;
+        while let Some(n) = node.0.as_ref() {
+            if pred(&n.value) {
+                node = &mut node.0.as_mut().unwrap().next;
+            } else {
+                let mut n = node.0.take().unwrap();
+                *node = n.next.take();
+                on_remove(&mut n.value);
+            }
+        }
+    }
+    /// 清空链表，每个元素交给回调后丢弃
+    pub fn clear(&mut self) {
+        while let Some(mut v) = self.link.pop() {
+            (self.on_remove)(&mut v);
+        }
+    }
+    /// 获取链表长度
+    pub fn len(&self) -> usize {
+        self.link.len()
+    }
+    /// 判断链表是否为空
+    pub fn is_empty(&self) -> bool {
+        self.link.is_empty()
+    }
+    /// 生成不可变迭代器
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.link.iter()
+    }
+    /// 查看内部链表
+    pub fn as_link(&self) -> &Link<T> {
+        &self.link
+    }
+    /// 取出内部链表，不调用回调
+    pub fn into_inner(mut self) -> Link<T> {
+        self.link.take()
+    }
+}
+impl<T, F> Drop for HookedLink<T, F>
+where
+    F: FnMut(&mut T) {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+impl<T: fmt::Debug, F> fmt::Debug for HookedLink<T, F>
+where
+    F: FnMut(&mut T) {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.link.fmt(f)
+    }
+}
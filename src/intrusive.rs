@@ -0,0 +1,207 @@
+//! 侵入式双向链表
+use alloc::rc::{Rc, Weak};
+use core::cell::RefCell;
+use core::fmt;
+use core::marker::PhantomData;
+
+/// 嵌入在元素结构体中的链接字段
+pub struct IntrusiveLink<T> {
+    state: RefCell<State<T>>,
+}
+struct State<T> {
+    next: Option<Rc<T>>,
+    prev: Weak<T>,
+    // 所在链表的标识，未加入链表时无法upgrade
+    owner: Weak<()>,
+}
+impl<T> IntrusiveLink<T> {
+    /// 创建未链接的字段
+    pub fn new() -> Self {
+        IntrusiveLink {state: RefCell::new(State {next: None, prev: Weak::new(), owner: Weak::new()})}
+    }
+    /// 判断所在的元素是否已在某个链表中
+    pub fn is_linked(&self) -> bool {
+        self.state.borrow().owner.strong_count() > 0
+    }
+}
+impl<T> Default for IntrusiveLink<T> {
+    fn default() -> Self {
+        IntrusiveLink::new()
+    }
+}
+impl<T> fmt::Debug for IntrusiveLink<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IntrusiveLink").field("linked", &self.is_linked()).finish()
+    }
+}
+/// 从元素中找到链表使用的链接字段，一个元素有多个链接字段时，
+/// 为每个字段定义一个适配器类型，元素就可以同时加入多个链表
+pub trait LinkAdapter<T> {
+    fn link(item: &T) -> &IntrusiveLink<T>;
+}
+/// 侵入式双向链表，链接保存在元素自身的IntrusiveLink字段中，链表不为元素分配任何内存，
+/// 元素由调用者通过Rc持有，持有元素的Rc就可以O(1)地把它从链表中摘下。
+/// 后继为强引用、前驱为弱引用，不会形成引用环
+/// # 例子
+/// ```
+/// use std::rc::Rc;
+/// use link::intrusive::{IntrusiveLink, IntrusiveList, LinkAdapter};
+/// struct Conn {
+///     id: u32,
+///     idle: IntrusiveLink<Conn>,
+/// }
+/// struct Idle;
+/// impl LinkAdapter<Conn> for Idle {
+///     fn link(c: &Conn) -> &IntrusiveLink<Conn> {
+///         &c.idle
+///     }
+/// }
+/// let conns: Vec<_> = (0..4).map(|id| Rc::new(Conn {id, idle: IntrusiveLink::new()})).collect();
+/// let mut idle: IntrusiveList<Conn, Idle> = IntrusiveList::new();
+/// for c in &conns {
+///     idle.push_back(Rc::clone(c)).ok().unwrap();
+/// }
+/// // 连接2变为活跃，O(1)摘下
+/// assert!(idle.unlink(&conns[2]));
+/// assert!(!conns[2].idle.is_linked());
+/// assert!(idle.push_back(Rc::clone(&conns[1])).is_err());
+/// assert_eq!(idle.iter().map(|c| c.id).collect::<Vec<_>>(), [0, 1, 3]);
+/// assert_eq!(idle.pop_front().map(|c| c.id), Some(0));
+/// ```
+pub struct IntrusiveList<T, A: LinkAdapter<T>> {
+    head: Option<Rc<T>>,
+    tail: Weak<T>,
+    len: usize,
+    token: Rc<()>,
+    marker: PhantomData<A>,
+}
+impl<T, A: LinkAdapter<T>> IntrusiveList<T, A> {
+    /// 创建空链表
+    pub fn new() -> Self {
+        IntrusiveList {head: None, tail: Weak::new(), len: 0, token: Rc::new(()), marker: PhantomData}
+    }
+    /// 获取链表长度，O(1)
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    /// 判断链表是否为空
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+    /// 判断元素是否在这个链表中，O(1)
+    pub fn contains(&self, item: &T) -> bool {
+        Weak::ptr_eq(&A::link(item).state.borrow().owner, &Rc::downgrade(&self.token))
+    }
+    /// 第一个元素
+    pub fn front(&self) -> Option<Rc<T>> {
+        self.head.clone()
+    }
+    /// 最后一个元素
+    pub fn back(&self) -> Option<Rc<T>> {
+        self.tail.upgrade()
+    }
+    /// 在链表的头部放入元素，元素已在某个链表中时原样返回
+    pub fn push_front(&mut self, item: Rc<T>) -> Result<(), Rc<T>> {
+        let link = A::link(&item);
+        if link.is_linked() {
+            return Err(item);
+        }
+        let mut st = link.state.borrow_mut();
+        st.owner = Rc::downgrade(&self.token);
+        st.prev = Weak::new();
+        st.next = self.head.take();
+        match &st.next {
+            Some(n) => A::link(n).state.borrow_mut().prev = Rc::downgrade(&item),
+            None => self.tail = Rc::downgrade(&item),
+        }
+        drop(st);
+        self.head = Some(item);
+        self.len += 1;
+        Ok(())
+    }
+    /// 在链表的尾部放入元素，元素已在某个链表中时原样返回
+    pub fn push_back(&mut self, item: Rc<T>) -> Result<(), Rc<T>> {
+        let link = A::link(&item);
+        if link.is_linked() {
+            return Err(item);
+        }
+        let mut st = link.state.borrow_mut();
+        st.owner = Rc::downgrade(&self.token);
+        st.prev = self.tail.clone();
+        st.next = None;
+        drop(st);
+        let weak = Rc::downgrade(&item);
+        match self.tail.upgrade() {
+            Some(t) => A::link(&t).state.borrow_mut().next = Some(item),
+            None => self.head = Some(item),
+        }
+        self.tail = weak;
+        self.len += 1;
+        Ok(())
+    }
+    /// 把元素从链表中摘下，O(1)，元素不在这个链表中时返回false
+    pub fn unlink(&mut self, item: &T) -> bool {
+        if !self.contains(item) {
+            return false;
+        }
+        let mut st = A::link(item).state.borrow_mut();
+        let next = st.next.take();
+        let prev = core::mem::take(&mut st.prev);
+        st.owner = Weak::new();
+        drop(st);
+        match &next {
+            Some(n) => A::link(n).state.borrow_mut().prev = prev.clone(),
+            None => self.tail = prev.clone(),
+        }
+        match prev.upgrade() {
+            Some(p) => A::link(&p).state.borrow_mut().next = next,
+            None => self.head = next,
+        }
+        self.len -= 1;
+        true
+    }
+    /// 弹出第一个元素
+    pub fn pop_front(&mut self) -> Option<Rc<T>> {
+        let item = self.head.clone()?;
+        self.unlink(&item);
+        Some(item)
+    }
+    /// 弹出最后一个元素
+    pub fn pop_back(&mut self) -> Option<Rc<T>> {
+        let item = self.tail.upgrade()?;
+        self.unlink(&item);
+        Some(item)
+    }
+    /// 摘下全部元素
+    pub fn clear(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+    /// 生成迭代器，依次返回各元素的Rc
+    pub fn iter(&self) -> IntrusiveIter<T, A> {
+        IntrusiveIter {next: self.head.clone(), marker: PhantomData}
+    }
+}
+impl<T, A: LinkAdapter<T>> Default for IntrusiveList<T, A> {
+    fn default() -> Self {
+        IntrusiveList::new()
+    }
+}
+/// 逐个摘下元素，使它们可以加入其他链表，也避免长链表递归析构
+impl<T, A: LinkAdapter<T>> Drop for IntrusiveList<T, A> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+/// IntrusiveList的迭代器
+pub struct IntrusiveIter<T, A: LinkAdapter<T>> {
+    next: Option<Rc<T>>,
+    marker: PhantomData<A>,
+}
+impl<T, A: LinkAdapter<T>> Iterator for IntrusiveIter<T, A> {
+    type Item = Rc<T>;
+    fn next(&mut self) -> Option<Rc<T>> {
+        let cur = self.next.take()?;
+        self.next = A::link(&cur).state.borrow().next.clone();
+        Some(cur)
+    }
+}
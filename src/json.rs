@@ -0,0 +1,273 @@
+//! 标量JSON数组与链表之间的转换
+use crate::{Link, LinkBuilder};
+use alloc::string::String;
+use core::fmt::{self, Write};
+use core::ops;
+use core::str::FromStr;
+
+/// JSON中的标量值
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+}
+/// 输出为JSON，NaN和无穷大没有对应的JSON表示，输出为null
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Null => f.write_str("null"),
+            Value::Bool(b) => write!(f, "{}", b),
+            Value::Number(n) if n.is_finite() => write!(f, "{}", n),
+            Value::Number(_) => f.write_str("null"),
+            Value::String(s) => {
+                f.write_char('"')?;
+                for c in s.chars() {
+                    match c {
+                        '"' => f.write_str("\\\"")?,
+                        '\\' => f.write_str("\\\\")?,
+                        '\n' => f.write_str("\\n")?,
+                        '\r' => f.write_str("\\r")?,
+                        '\t' => f.write_str("\\t")?,
+                        c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+                        c => f.write_char(c)?,
+                    }
+                }
+                f.write_char('"')
+            },
+        }
+    }
+}
+/// 解析错误，position为出错处的字节偏移
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct JsonError {
+    pub position: usize,
+    pub message: &'static str,
+}
+impl fmt::Display for JsonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at byte {}", self.message, self.position)
+    }
+}
+impl core::error::Error for JsonError {}
+/// 由标量组成的JSON数组，解析为Value链表，Display输出紧凑的JSON
+/// # 例子
+/// ```
+/// use link::json::{JsonList, Value};
+/// let l: JsonList = r#" [1, -2.5e1, "a\"bé", true, null] "#.parse().unwrap();
+/// assert_eq!(l.len(), 5);
+/// assert_eq!(l[1], Value::Number(-25.0));
+/// assert_eq!(l[2], Value::String("a\"bé".to_string()));
+/// assert_eq!(l.to_string(), r#"[1,-25,"a\"bé",true,null]"#);
+/// let err = "[1, [2]]".parse::<JsonList>().unwrap_err();
+/// assert_eq!(err.position, 4);
+/// assert!(r#"["\u+041"]"#.parse::<JsonList>().is_err());
+/// assert_eq!(r#"["\u0041"]"#.parse::<JsonList>().unwrap()[0], Value::String("A".to_string()));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct JsonList(pub Link<Value>);
+impl JsonList {
+    /// 解析JSON数组，数组元素只能是标量
+    pub fn parse(s: &str) -> Result<Self, JsonError> {
+        let mut p = Parser {s, pos: 0};
+        let list = p.array()?;
+        p.skip_ws();
+        match p.pos == s.len() {
+            true => Ok(JsonList(list)),
+            false => Err(p.error("trailing characters")),
+        }
+    }
+    /// 取出内部链表
+    pub fn into_inner(self) -> Link<Value> {
+        self.0
+    }
+}
+impl FromStr for JsonList {
+    type Err = JsonError;
+    fn from_str(s: &str) -> Result<Self, JsonError> {
+        JsonList::parse(s)
+    }
+}
+impl From<Link<Value>> for JsonList {
+    fn from(link: Link<Value>) -> Self {
+        JsonList(link)
+    }
+}
+impl ops::Deref for JsonList {
+    type Target = Link<Value>;
+    fn deref(&self) -> &Link<Value> {
+        &self.0
+    }
+}
+impl ops::DerefMut for JsonList {
+    fn deref_mut(&mut self) -> &mut Link<Value> {
+        &mut self.0
+    }
+}
+impl fmt::Display for JsonList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_char('[')?;
+        for (i, v) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_char(',')?;
+            }
+            write!(f, "{}", v)?;
+        }
+        f.write_char(']')
+    }
+}
+/// 递归下降解析器，pos总是位于字符边界上
+struct Parser<'a> {
+    s: &'a str,
+    pos: usize,
+}
+impl<'a> Parser<'a> {
+    fn error(&self, message: &'static str) -> JsonError {
+        JsonError {position: self.pos, message}
+    }
+    fn peek(&self) -> Option<u8> {
+        self.s.as_bytes().get(self.pos).copied()
+    }
+    fn skip_ws(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.peek() {
+            self.pos += 1;
+        }
+    }
+    fn expect(&mut self, b: u8, message: &'static str) -> Result<(), JsonError> {
+        self.skip_ws();
+        match self.peek() == Some(b) {
+            true => {
+                self.pos += 1;
+                Ok(())
+            },
+            false => Err(self.error(message)),
+        }
+    }
+    fn array(&mut self) -> Result<Link<Value>, JsonError> {
+        self.expect(b'[', "expected '['")?;
+        let mut builder = LinkBuilder::new();
+        self.skip_ws();
+        if self.peek() == Some(b']') {
+            self.pos += 1;
+            return Ok(builder.finish());
+        }
+        loop {
+            builder.push(self.value()?);
+            self.skip_ws();
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b']') => {
+                    self.pos += 1;
+                    return Ok(builder.finish());
+                },
+                _ => return Err(self.error("expected ',' or ']'")),
+            }
+        }
+    }
+    fn value(&mut self) -> Result<Value, JsonError> {
+        self.skip_ws();
+        let rest = &self.s[self.pos..];
+        for (word, v) in [("null", Value::Null), ("true", Value::Bool(true)), ("false", Value::Bool(false))] {
+            if rest.starts_with(word) {
+                self.pos += word.len();
+                return Ok(v);
+            }
+        }
+        match self.peek() {
+            Some(b'"') => self.string().map(Value::String),
+            Some(b'-' | b'0'..=b'9') => self.number(),
+            Some(b'[' | b'{') => Err(self.error("nested values are not supported")),
+            _ => Err(self.error("expected a value")),
+        }
+    }
+    fn digits(&mut self) -> usize {
+        let start = self.pos;
+        while let Some(b'0'..=b'9') = self.peek() {
+            self.pos += 1;
+        }
+        self.pos - start
+    }
+    fn number(&mut self) -> Result<Value, JsonError> {
+        let start = self.pos;
+        if self.peek() == Some(b'-') {
+            self.pos += 1;
+        }
+        let int = self.pos;
+        if self.digits() == 0 || (self.s.as_bytes()[int] == b'0' && self.pos - int > 1) {
+            return Err(JsonError {position: start, message: "invalid number"});
+        }
+        if self.peek() == Some(b'.') {
+            self.pos += 1;
+            if self.digits() == 0 {
+                return Err(self.error("invalid number"));
+            }
+        }
+        if let Some(b'e' | b'E') = self.peek() {
+            self.pos += 1;
+            if let Some(b'+' | b'-') = self.peek() {
+                self.pos += 1;
+            }
+            if self.digits() == 0 {
+                return Err(self.error("invalid number"));
+            }
+        }
+        self.s[start..self.pos].parse().map(Value::Number)
+            .map_err(|_| JsonError {position: start, message: "invalid number"})
+    }
+    fn hex4(&mut self) -> Result<u32, JsonError> {
+        let hex = self.s.get(self.pos..self.pos + 4)
+            .filter(|h| h.bytes().all(|b| b.is_ascii_hexdigit()))
+            .ok_or_else(|| self.error("invalid escape"))?;
+        let v = u32::from_str_radix(hex, 16).map_err(|_| self.error("invalid escape"))?;
+        self.pos += 4;
+        Ok(v)
+    }
+    fn string(&mut self) -> Result<String, JsonError> {
+        self.pos += 1;
+        let mut out = String::new();
+        loop {
+            let c = self.s[self.pos..].chars().next().ok_or_else(|| self.error("unterminated string"))?;
+            match c {
+                '"' => {
+                    self.pos += 1;
+                    return Ok(out);
+                },
+                '\\' => {
+                    self.pos += 1;
+                    let e = self.peek().ok_or_else(|| self.error("unterminated string"))?;
+                    self.pos += 1;
+                    out.push(match e {
+                        b'"' => '"',
+                        b'\\' => '\\',
+                        b'/' => '/',
+                        b'b' => '\u{8}',
+                        b'f' => '\u{c}',
+                        b'n' => '\n',
+                        b'r' => '\r',
+                        b't' => '\t',
+                        b'u' => {
+                            let mut u = self.hex4()?;
+                            // 代理对
+                            if (0xd800..0xdc00).contains(&u) && self.s[self.pos..].starts_with("\\u") {
+                                self.pos += 2;
+                                let low = self.hex4()?;
+                                if !(0xdc00..0xe000).contains(&low) {
+                                    return Err(self.error("invalid surrogate pair"));
+                                }
+                                u = 0x10000 + ((u - 0xd800) << 10) + (low - 0xdc00);
+                            }
+                            char::from_u32(u).ok_or_else(|| self.error("invalid escape"))?
+                        },
+                        _ => return Err(self.error("invalid escape")),
+                    });
+                },
+                c if (c as u32) < 0x20 => return Err(self.error("control character in string")),
+                c => {
+                    self.pos += c.len_utf8();
+                    out.push(c);
+                },
+            }
+        }
+    }
+}
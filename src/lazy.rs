@@ -0,0 +1,161 @@
+//! 按需求值的惰性无限链表
+use crate::Link;
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use core::cell::{Ref, RefCell};
+use core::fmt;
+
+/// 惰性无限链表，第i个元素由生成闭包在第一次访问时计算并缓存。
+///
+/// 与RingQueue相同，内部用两个链表保存已求值的部分：realized按顺序保存较早的元素，
+/// recent按从新到旧保存新求值的元素。求值只在recent的头部压入，每个新元素O(1)；
+/// get(i)从所在链表的头部找到元素，按索引顺序逐个get、每次只求值下一个元素时为O(1)，
+/// 其余情况下为O(i)或O(已求值的元素个数 - i)，已经求值的部分应通过realized().iter()顺序访问。
+/// realized和take需要完整的有序链表，
+/// 先把recent反转后接到realized的尾部，为O(已求值的元素个数)
+/// # 例子
+/// ```
+/// use link::lazy::LazyLink;
+/// let mut squares = LazyLink::new(|i| i * i);
+/// assert_eq!(squares.get(3), &9);
+/// assert_eq!(squares.realized_len(), 4);
+/// assert_eq!(format!("{:?}", squares.take(5)), "[0, 1, 4, 9, 16]");
+/// let sum: usize = (0..1000).map(|i| *squares.get(i)).sum();
+/// assert_eq!(sum, 332_833_500);
+/// ```
+pub struct LazyLink<T> {
+    realized: Link<T>,
+    settled: usize,
+    recent: Link<T>,
+    len: usize,
+    gen: Box<dyn FnMut(usize) -> T>,
+}
+impl<T> LazyLink<T> {
+    /// 用生成闭包创建惰性链表，闭包的参数为元素的索引
+    pub fn new<F>(gen: F) -> Self
+    where
+        F: FnMut(usize) -> T + 'static {
+        LazyLink {realized: Link::new(), settled: 0, recent: Link::new(), len: 0, gen: Box::new(gen)}
+    }
+    /// 已经求值的元素个数
+    pub fn realized_len(&self) -> usize {
+        self.len
+    }
+    /// 把recent中的元素按顺序接到realized的尾部
+    fn settle(&mut self) {
+        if self.settled < self.len {
+            self.recent.reverse();
+            self.realized.concat(self.recent.take());
+            self.settled = self.len;
+        }
+    }
+    /// 查看已经求值的部分，按顺序排列
+    pub fn realized(&mut self) -> &Link<T> {
+        self.settle();
+        &self.realized
+    }
+    /// 确保前n个元素已经求值，每个新元素O(1)
+    pub fn force(&mut self, n: usize) {
+        for i in self.len..n {
+            self.recent.push((self.gen)(i));
+        }
+        self.len = self.len.max(n);
+    }
+    /// 获取第i个元素，必要时求值
+    pub fn get(&mut self, i: usize) -> &T {
+        self.force(i + 1);
+        match i < self.settled {
+            true => &self.realized[i],
+            false => &self.recent[self.len - 1 - i],
+        }
+    }
+    /// 把前n个元素复制为普通链表
+    pub fn take(&mut self, n: usize) -> Link<T>
+    where
+        T: Clone {
+        self.force(n);
+        self.settle();
+        self.realized.iter().take(n).collect()
+    }
+}
+/// 格式化表示，只显示已经求值的部分
+/// # 例子
+/// ```
+/// use link::lazy::LazyLink;
+/// let mut l = LazyLink::new(|i| i);
+/// l.force(2);
+/// assert_eq!(format!("{:?}", l), "[0, 1, ..]");
+/// ```
+impl<T: fmt::Debug> fmt::Debug for LazyLink<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut list = f.debug_list();
+        list.entries(&self.realized);
+        list.entries(self.recent.iter().rev());
+        list.entry(&format_args!(".."));
+        list.finish()
+    }
+}
+/// 递推闭包，参数为元素的索引和已求值的前缀
+type Recurrence<T> = Box<dyn Fn(usize, &Link<T>) -> T>;
+struct Memo<T> {
+    realized: Link<T>,
+    len: usize,
+    f: Recurrence<T>,
+}
+/// 带记忆的递推链表，第i个元素由闭包根据索引和已求值的前缀计算，
+/// 每个元素只计算一次，克隆出的MemoLink通过Rc共享同一份缓存
+/// # 例子
+/// ```
+/// use link::*;
+/// use link::lazy::MemoLink;
+/// let fib = MemoLink::new(|i, prev: &Link<u64>| {
+///     if i < 2 { i as u64 } else { prev[i - 1] + prev[i - 2] }
+/// });
+/// let shared = fib.clone();
+/// assert_eq!(*fib.get(10), 55);
+/// assert_eq!(shared.realized_len(), 11);
+/// ```
+pub struct MemoLink<T> {
+    inner: Rc<RefCell<Memo<T>>>,
+}
+impl<T> Clone for MemoLink<T> {
+    fn clone(&self) -> Self {
+        MemoLink {inner: Rc::clone(&self.inner)}
+    }
+}
+impl<T> MemoLink<T> {
+    /// 用递推闭包创建链表，闭包的参数为元素的索引和已求值的前缀
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(usize, &Link<T>) -> T + 'static {
+        let memo = Memo {realized: Link::new(), len: 0, f: Box::new(f)};
+        MemoLink {inner: Rc::new(RefCell::new(memo))}
+    }
+    /// 已经求值的元素个数
+    pub fn realized_len(&self) -> usize {
+        self.inner.borrow().len
+    }
+    /// 确保索引不超过n的元素都已经求值，每个新元素需要O(n)追加到尾部
+    pub fn force_to(&self, n: usize) {
+        let mut memo = self.inner.borrow_mut();
+        for i in memo.len..=n {
+            let v = (memo.f)(i, &memo.realized);
+            memo.realized.push_back(v);
+            memo.len += 1;
+        }
+    }
+    /// 获取第i个元素，必要时求值
+    pub fn get(&self, i: usize) -> Ref<'_, T> {
+        self.force_to(i);
+        Ref::map(self.inner.borrow(), |m| &m.realized[i])
+    }
+    /// 把前n个元素复制为普通链表
+    pub fn take(&self, n: usize) -> Link<T>
+    where
+        T: Clone {
+        if n > 0 {
+            self.force_to(n - 1);
+        }
+        self.inner.borrow().realized.iter().take(n).collect()
+    }
+}
@@ -1,5 +1,101 @@
 //! 这是一个rust的单向链表的实现，本链表实现了集合的基本功能。
+//!
+//! 默认启用std特性，关闭后本库为no_std，只依赖alloc。
+#![cfg_attr(not(feature = "std"), no_std)]
+#![forbid(unsafe_code)]
+extern crate alloc;
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+/// 记录结构性操作的tracing事件，字段为操作名、操作前的链表长度和可选的索引，
+/// 未启用tracing特性时展开为空。求长度需要O(n)，只在该事件确实会被记录时才计算
+macro_rules! trace_op {
+    ($op:expr, $link:expr) => {
+        #[cfg(feature = "tracing")]
+        if tracing::enabled!(target: "link", tracing::Level::TRACE) {
+            tracing::trace!(target: "link", op = $op, len = $link.len());
+        }
+    };
+    ($op:expr, $link:expr, $index:expr) => {
+        #[cfg(feature = "tracing")]
+        if tracing::enabled!(target: "link", tracing::Level::TRACE) {
+            tracing::trace!(target: "link", op = $op, len = $link.len(), index = $index);
+        }
+    };
+}
+/// 增加metrics特性的全局计数器，未启用metrics特性时展开为空
+macro_rules! count_op {
+    ($counter:ident) => {
+        #[cfg(feature = "metrics")]
+        $crate::metrics::$counter.fetch_add(1, core::sync::atomic::Ordering::Relaxed);
+    };
+}
+pub mod arena;
+pub mod assoc;
+#[cfg(feature = "async")]
+pub mod async_link;
+#[cfg(feature = "async")]
+pub use async_link::AsyncLink;
+#[cfg(feature = "std")]
+pub mod async_queue;
+pub mod bounded;
+pub use bounded::{BoundedLink, CapacityExceeded, IntegrityError, Overflow};
+pub mod bst;
+pub mod builder;
+pub use builder::LinkBuilder;
+#[cfg(feature = "std")]
+pub mod codec;
+#[cfg(feature = "concurrent")]
+pub mod concurrent;
+pub mod cursor;
+pub use cursor::{Bookmark, CursorMut, StaleHandle};
+#[cfg(feature = "std")]
+pub mod expiry;
+#[cfg(feature = "bytes")]
+pub mod buf;
+#[cfg(feature = "csv")]
+mod csv_io;
+#[cfg(feature = "defmt")]
+mod defmt_impl;
+#[cfg(feature = "std")]
+pub mod hashed;
+pub mod hooked;
+pub use hooked::HookedLink;
+pub mod intrusive;
+#[cfg(feature = "json")]
+pub mod json;
+pub mod lazy;
+#[cfg(feature = "std")]
+pub mod map;
+#[cfg(feature = "metrics")]
+pub mod metrics;
+#[cfg(feature = "rayon")]
+mod par;
+pub mod node_ref;
+pub use node_ref::NodeRef;
+pub mod numeric;
+pub use numeric::Numeric;
+pub mod persistent;
+pub mod pool;
+#[cfg(feature = "rand")]
+mod random;
+pub mod ring;
+#[cfg(feature = "zeroize")]
+pub mod secret;
+#[cfg(feature = "serde")]
+mod serde_impl;
+pub mod stats;
+pub mod timerwheel;
+pub mod transaction;
+pub use transaction::Transaction;
+pub mod weak;
+pub use weak::WeakLink;
 /// 链表结构体
+///
+/// 每个结点单独分配在堆上，元素留在链表中期间它的地址不会改变：
+/// reverse、sort、split_off、CursorMut的插入删除和拼接等操作只修改结点之间的链接，不移动结点。
+/// 只有把元素移出链表（pop、delete、into_iter等）、替换整个结点或用compact重新分配全部结点时元素才会移动，
+/// 通过NodeRef可以获取并比较元素的地址
 #[derive(Clone)]
 pub struct Link<T> (Option<Box<Node<T>>>);
 ///节点结构体
@@ -11,6 +107,8 @@ pub struct Node<T> {
 impl<T> Node<T> {
     /// 创建节点
     fn new(value:T, data: Option<Box<Self>>) -> Self {
+        count_op!(ALLOCATIONS);
+        count_op!(MOVES);
         Self {value, next: Link::from(data)}
     }
     /// 节点转化为链表
@@ -75,13 +173,58 @@ impl<T> Node<T> {
     /// node.skip_mut(1).unwrap().value = 5;
     /// assert_eq!(l, link![1,2,5,4]);
     /// ```
-    pub fn skip_mut(self: &mut Self, n: usize) -> Option<&mut Self>{
+    pub fn skip_mut(&mut self, n: usize) -> Option<&mut Self>{
         let mut node = self;
         for _ in 0..n {
             node = node.next.0.as_mut()?;
         }
         Some(node)
     }
+    /// 获取从本节点起第i个元素（本节点为第0个）的不可变引用，超出范围时返回None
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let l: Link<usize> = link![1,2,3,4];
+    /// let node = l.get(1).unwrap();
+    /// assert_eq!(node.get(2), Some(&4));
+    /// assert_eq!(node.get(3), None);
+    /// assert_eq!(node[1], 3);
+    /// assert_eq!(node.len(), 3);
+    /// ```
+    pub fn get(&self, i: usize) -> Option<&T> {
+        Some(&self.skip(i)?.value)
+    }
+    /// 获取从本节点起第i个元素的可变引用，超出范围时返回None
+    pub fn get_mut(&mut self, i: usize) -> Option<&mut T> {
+        Some(&mut self.skip_mut(i)?.value)
+    }
+    /// 从本节点起（包括本节点）到链表末尾的元素个数，节点链总不为空，因此没有is_empty
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        1 + self.next.len()
+    }
+    /// 与skip相同，失败时返回从本节点起的链的长度
+    fn try_skip(&self, n: usize) -> Result<&Self, usize> {
+        let mut node = self;
+        for walked in 1..=n {
+            node = match node.next.0.as_deref() {
+                Some(next) => next,
+                None => return Err(walked),
+            };
+        }
+        Ok(node)
+    }
+    /// 与skip_mut相同，失败时返回从本节点起的链的长度
+    fn try_skip_mut(&mut self, n: usize) -> Result<&mut Self, usize> {
+        let mut node = self;
+        for walked in 1..=n {
+            node = match node.next.0.as_deref_mut() {
+                Some(next) => next,
+                None => return Err(walked),
+            };
+        }
+        Ok(node)
+    }
     /// 插入子节点
     /// # 例子
     /// ```
@@ -108,6 +251,7 @@ impl<T> Node<T> {
     pub fn pop_next(&mut self) -> Option<T> {
         let n = self.next.0.take()?;
         self.next = n.next;
+        count_op!(MOVES);
         Some(n.value)
     }
 }
@@ -119,6 +263,7 @@ impl<T> Link<T> {
     /// 获取链表的尾节点的可变引用
     fn end_node(mut node: &mut Box<Node<T>>) -> &mut Box<Node<T>> {
         while let Some(ref mut t) = node.next.0 {
+            count_op!(STEPS);
             node = t;
         }
         node
@@ -128,10 +273,12 @@ impl<T> Link<T> {
     /// i: 目标节点相对起始节点的索引
     /// # 输出
     /// Option<&Box<Node<T>>>: some(目标节点的不可变引用)，当目标节点获取失败(输入错误)时为None
+    #[allow(clippy::borrowed_box)]
     pub fn get(&self, i: usize) -> Option<&Box<Node<T>>> {
         let mut node = self.0.as_ref()?;
         for _ in 0..i {
             node = node.next.0.as_ref()?;
+            count_op!(STEPS);
         }
         Some(node)
     }
@@ -144,12 +291,34 @@ impl<T> Link<T> {
         let mut node = self.0.as_mut()?;
         for _ in 0..i {
             node = node.next.0.as_mut()?;
+            count_op!(STEPS);
         }
         Some(node)
     }
-    /// 引发超出链表的范围的恐慌
-    fn out_of_range(index: usize) -> ! {
-        panic!("index {} out of range for Link", index);
+    /// 引发超出链表的范围的恐慌，信息中包含链表长度
+    fn out_of_range(index: usize, len: usize) -> ! {
+        panic!("index {} out of range for Link of length {}", index, len);
+    }
+    /// 与get相同，但在debug构建中索引超出范围时恐慌（信息包含链表长度），
+    /// release构建中只返回None而不做额外检查，适合确信索引有效的热点路径
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let l: Link<isize> = link![0, 1, 2];
+    /// assert_eq!(l.debug_checked_get(2), Some(&2));
+    /// ```
+    /// ```should_panic
+    /// use link::*;
+    /// let l: Link<isize> = link![0, 1, 2];
+    /// # if !cfg!(debug_assertions) { panic!() }
+    /// l.debug_checked_get(7);
+    /// ```
+    pub fn debug_checked_get(&self, i: usize) -> Option<&T> {
+        let v = self.at(i);
+        if cfg!(debug_assertions) && v.is_none() {
+            Self::out_of_range(i, self.len());
+        }
+        v
     }
     /// 创建空链表
     /// # 例子
@@ -165,16 +334,71 @@ impl<T> Link<T> {
     pub fn empty(&self) -> bool {
         self.0.is_none()
     }
+    /// 判断链表是否为空，与empty相同
+    pub fn is_empty(&self) -> bool {
+        self.empty()
+    }
     /// 获取链表长度
     pub fn len(&self) -> usize {
         let mut len:usize = 0;
         let mut node = self.0.as_ref();
         while let Some(n) = node {
             node = n.next.0.as_ref();
+            count_op!(STEPS);
             len += 1;
         }
         len
     }
+    /// 链表结点占用的堆内存字节数，不包括元素自身另外持有的堆内存
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let l: Link<u64> = link![1, 2, 3];
+    /// assert_eq!(l.memory_usage(), 3 * std::mem::size_of::<Node<u64>>());
+    /// ```
+    pub fn memory_usage(&self) -> usize {
+        self.len() * core::mem::size_of::<Node<T>>()
+    }
+    /// 重新分配所有结点：先把元素移出并释放全部旧结点，再按顺序连续分配新结点，
+    /// 用于在大量增删之后恢复遍历时的内存局部性，结点数与memory_usage不变
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let mut l: Link<isize> = (0..5).collect();
+    /// l.delete(2);
+    /// l.insert(1, 9);
+    /// l.compact();
+    /// assert_eq!(format!("{:?}", l), "[0, 9, 1, 3, 4]");
+    /// ```
+    pub fn compact(&mut self) {
+        let values: Vec<T> = self.take().into_iter().collect();
+        *self = values.into_iter().collect();
+    }
+    /// 按节点折叠链表，闭包可以访问节点本身（如后继节点），用于需要结构信息的统计
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let l: Link<usize> = link![1, 1, 2, 2, 2, 3];
+    /// // 统计相邻相等的节点对数
+    /// let pairs = l.fold_nodes(0, |acc, node| {
+    ///     match node.next() {
+    ///         Some(n) if n.value == node.value => acc + 1,
+    ///         _ => acc,
+    ///     }
+    /// });
+    /// assert_eq!(pairs, 3);
+    /// ```
+    pub fn fold_nodes<B, F>(&self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, &Node<T>) -> B {
+        let mut acc = init;
+        let mut node = self.0.as_deref();
+        while let Some(n) = node {
+            acc = f(acc, n);
+            node = n.next.0.as_deref();
+        }
+        acc
+    }
     /// 拼接a, b两个链表，相当于a = a + b
     /// # 例子
     /// ```
@@ -185,6 +409,7 @@ impl<T> Link<T> {
     /// assert_eq!(format!("{:?}", a), "[1, 2, 3, 4]");
     /// ```
     pub fn concat(&mut self, other: Self) {
+        trace_op!("concat", self);
         match self.0.as_mut() {
             //空链表
             None => *self = other,
@@ -201,6 +426,7 @@ impl<T> Link<T> {
     /// assert_eq!(a, link![1]);
     /// assert_eq!(b, link![2, 3]);
     /// ```
+    #[must_use = "the split-off tail is dropped if the result is unused"]
     pub fn split_off(&mut self, at: usize) -> Self {
         if let Some(node) = self.get_mut(at) {
             Link::from(node.next.0.take())
@@ -217,11 +443,465 @@ impl<T> Link<T> {
     /// assert_eq!(format!("{:?}", a), "[]");
     /// assert_eq!(format!("{:?}", b), "[0, 1, 2]");
     /// ```
+    /// 忽略返回值会直接丢弃全部元素
+    /// ```compile_fail
+    /// #![deny(unused_must_use)]
+    /// use link::*;
+    /// let mut a: Link<isize> = link![0, 1, 2];
+    /// a.take();
+    /// ```
+    #[must_use = "the taken elements are dropped if the result is unused"]
     pub fn take(&mut self) -> Self {
         Link::from(self.0.take())
     }
-    /// 在链表的尾部追加元素
+    /// 交换两个链表的全部内容，只交换头指针，O(1)，
+    /// 与core::mem::swap等价，不移动任何结点或元素
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// // 双缓冲：处理front的同时向back写入
+    /// let mut front: Link<isize> = link![1, 2];
+    /// let mut back: Link<isize> = Link::new();
+    /// for v in front.drain_all() {
+    ///     back.push(v * 10);
+    /// }
+    /// front.swap_with(&mut back);
+    /// assert_eq!(format!("{:?}", front), "[20, 10]");
+    /// assert!(back.is_empty());
+    /// ```
+    pub fn swap_with(&mut self, other: &mut Self) {
+        core::mem::swap(self, other);
+    }
+    /// 泄漏链表，返回头结点的可变引用，空链表返回None，类似Vec::leak，
+    /// 适用于启动时构建一次、之后全局使用的数据
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let l: Link<isize> = link![1, 2, 3];
+    /// let head: &'static mut Node<isize> = l.leak().unwrap();
+    /// head.value = 0;
+    /// assert_eq!(head.next().unwrap().value, 2);
+    /// ```
+    pub fn leak<'a>(mut self) -> Option<&'a mut Node<T>> {
+        self.0.take().map(Box::leak)
+    }
+    /// 原地反转链表，只修改结点的链接
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let mut a: Link<isize> = link![0, 1, 2];
+    /// a.reverse();
+    /// assert_eq!(format!("{:?}", a), "[2, 1, 0]");
+    /// ```
+    pub fn reverse(&mut self) {
+        let mut rest = self.take();
+        while let Some(mut node) = rest.0.take() {
+            rest = node.next.take();
+            node.next = self.take();
+            self.0 = Some(node);
+        }
+    }
+    /// 与reverse相同，名字表明直接修改原链表
+    pub fn reverse_in_place(&mut self) {
+        self.reverse();
+    }
+    /// 消耗链表并返回反转后的链表，复用原有结点
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let a: Link<isize> = link![0, 1, 2];
+    /// let b = a.reverse_consuming();
+    /// assert_eq!(format!("{:?}", b), "[2, 1, 0]");
+    /// ```
+    /// 原链表已被移动，不能再使用
+    /// ```compile_fail
+    /// use link::*;
+    /// let a: Link<isize> = link![0, 1, 2];
+    /// let b = a.reverse_consuming();
+    /// a.len();
+    /// ```
+    #[must_use = "the reversed list is dropped if the result is unused; use `reverse_in_place` instead"]
+    pub fn reverse_consuming(mut self) -> Self {
+        self.reverse();
+        self
+    }
+    /// 原地排序，排序是稳定的
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let mut l: Link<isize> = link![3, 1, 4, 1, 5, 9, 2, 6];
+    /// l.sort();
+    /// assert_eq!(format!("{:?}", l), "[1, 1, 2, 3, 4, 5, 6, 9]");
+    /// ```
+    pub fn sort(&mut self)
+    where
+        T: Ord {
+        self.sort_by(T::cmp);
+    }
+    /// 用比较函数原地排序，排序是稳定的。自底向上归并，只修改结点的链接，
+    /// 不移动或复制元素，也不分配内存，时间复杂度O(n log n)
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// // 元素不需要实现Clone
+    /// struct Job(u8, &'static str);
+    /// let mut l: Link<Job> = link![Job(2, "a"), Job(1, "b"), Job(2, "c"), Job(1, "d")];
+    /// l.sort_by(|x, y| x.0.cmp(&y.0));
+    /// assert_eq!(l.iter().map(|j| j.1).collect::<String>(), "bdac");
+    /// ```
+    pub fn sort_by<F>(&mut self, mut cmp: F)
+    where
+        F: FnMut(&T, &T) -> cmp::Ordering {
+        trace_op!("sort", self);
+        // bins[i]为空或长度为2^i的有序段，下标越大的段中的元素在原链表中越靠前
+        let mut bins: [Self; usize::BITS as usize] = core::array::from_fn(|_| Link::new());
+        let mut rest = self.take();
+        while let Some(mut node) = rest.0.take() {
+            rest = node.next.take();
+            let mut carry = Link(Some(node));
+            let mut i = 0;
+            while !bins[i].is_empty() {
+                carry = Self::merge_by(bins[i].take(), carry, &mut cmp);
+                i += 1;
+            }
+            bins[i] = carry;
+        }
+        for b in bins {
+            *self = Self::merge_by(b, self.take(), &mut cmp);
+        }
+    }
+    /// 原地排序，与sort相同
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let mut a: Link<isize> = link![2, 0, 1];
+    /// a.sort_in_place();
+    /// assert_eq!(format!("{:?}", a), "[0, 1, 2]");
+    /// let b = link![5, 3, 4].sort_consuming();
+    /// assert_eq!(format!("{:?}", b), "[3, 4, 5]");
+    /// ```
+    pub fn sort_in_place(&mut self)
+    where
+        T: Ord {
+        self.sort();
+    }
+    /// 消耗链表并返回排好序的链表
+    #[must_use = "the sorted list is dropped if the result is unused; use `sort_in_place` instead"]
+    pub fn sort_consuming(mut self) -> Self
+    where
+        T: Ord {
+        self.sort_in_place();
+        self
+    }
+    /// 原地修改每个元素
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let mut a: Link<isize> = link![1, 2];
+    /// a.map_in_place(|v| *v *= 10);
+    /// assert_eq!(format!("{:?}", a), "[10, 20]");
+    /// let b: Link<String> = a.map_consuming(|v| v.to_string());
+    /// assert_eq!(format!("{:?}", b), r#"["10", "20"]"#);
+    /// ```
+    pub fn map_in_place<F>(&mut self, f: F)
+    where
+        F: FnMut(&mut T) {
+        self.iter_mut().for_each(f);
+    }
+    /// 消耗链表，把每个元素转化为新链表中的元素
+    /// ```compile_fail
+    /// #![deny(unused_must_use)]
+    /// use link::*;
+    /// let a: Link<isize> = link![1, 2];
+    /// a.map_consuming(|v| v + 1);
+    /// ```
+    #[must_use = "the mapped list is dropped if the result is unused; use `map_in_place` to modify elements"]
+    pub fn map_consuming<U, F>(self, f: F) -> Link<U>
+    where
+        F: FnMut(T) -> U {
+        self.into_iter().map(f).collect()
+    }
+    /// 获取指向第i个结点的链接的可变引用，i等于长度时为尾部的空链接，i超出长度时返回None
+    fn slot_mut(&mut self, i: usize) -> Option<&mut Self> {
+        let mut slot = self;
+        for _ in 0..i {
+            slot = &mut slot.0.as_mut()?.next;
+            count_op!(STEPS);
+        }
+        Some(slot)
+    }
+    /// 原地反转索引在range内的元素，只修改结点的链接，其余部分不变
+    /// # Panics
+    /// range的起点大于终点或终点超出链表长度时恐慌
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let mut a: Link<isize> = link![0, 1, 2, 3, 4, 5];
+    /// a.reverse_range(1..4);
+    /// assert_eq!(format!("{:?}", a), "[0, 3, 2, 1, 4, 5]");
+    /// a.reverse_range(4..6);
+    /// assert_eq!(format!("{:?}", a), "[0, 3, 2, 1, 5, 4]");
+    /// a.reverse_range(2..2);
+    /// assert_eq!(format!("{:?}", a), "[0, 3, 2, 1, 5, 4]");
+    /// ```
+    /// ```should_panic
+    /// use link::*;
+    /// let mut a: Link<isize> = link![0, 1, 2];
+    /// a.reverse_range(2..1); // range start 2 > end 1
+    /// ```
+    pub fn reverse_range(&mut self, range: core::ops::Range<usize>) {
+        assert!(range.start <= range.end, "range start {} > end {}", range.start, range.end);
+        if self.slot_mut(range.start).is_none() {
+            Self::out_of_range(range.start, self.len());
+        }
+        let slot = self.slot_mut(range.start).unwrap();
+        let mut rest = slot.take();
+        let mut mid = Link::new();
+        for taken in 0..range.len() {
+            match rest.0.take() {
+                Some(mut node) => {
+                    rest = node.next.take();
+                    node.next = mid.take();
+                    mid.0 = Some(node);
+                },
+                None => {
+                    // 恢复已经摘下的部分后再恐慌
+                    mid.reverse();
+                    *slot = mid;
+                    Self::out_of_range(range.end - 1, range.start + taken);
+                },
+            }
+        }
+        mid.concat(rest);
+        *slot = mid;
+    }
+    /// 把索引from处的结点移动到索引to处，一次遍历完成，只修改结点的链接，
+    /// 相当于先delete(from)再insert(to)，索引超出范围时返回false且不修改链表
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let mut a: Link<isize> = link![0, 1, 2, 3, 4];
+    /// assert!(a.move_item(1, 3));
+    /// assert_eq!(format!("{:?}", a), "[0, 2, 3, 1, 4]");
+    /// assert!(a.move_item(4, 0));
+    /// assert_eq!(format!("{:?}", a), "[4, 0, 2, 3, 1]");
+    /// assert!(!a.move_item(0, 5));
+    /// assert_eq!(format!("{:?}", a), "[4, 0, 2, 3, 1]");
+    /// ```
+    pub fn move_item(&mut self, from: usize, to: usize) -> bool {
+        let (lo, hi) = (from.min(to), from.max(to));
+        let slot = match self.slot_mut(lo) {
+            Some(slot) if slot.0.is_some() => slot,
+            _ => return false,
+        };
+        let mut seg = slot.take();
+        if from <= to {
+            let mut node = seg.0.take().unwrap();
+            let mut rest = node.next.take();
+            match rest.slot_mut(hi - lo) {
+                Some(s) => {
+                    node.next = s.take();
+                    s.0 = Some(node);
+                    *slot = rest;
+                    true
+                },
+                None => {
+                    node.next = rest;
+                    slot.0 = Some(node);
+                    false
+                },
+            }
+        } else {
+            match seg.slot_mut(hi - lo) {
+                Some(s) if s.0.is_some() => {
+                    let mut node = s.0.take().unwrap();
+                    *s = node.next.take();
+                    node.next = seg;
+                    slot.0 = Some(node);
+                    true
+                },
+                _ => {
+                    *slot = seg;
+                    false
+                },
+            }
+        }
+    }
+    /// 稳定地原地划分链表，满足条件的元素移到不满足的元素之前，两组内部保持原有顺序，
+    /// 只修改结点的链接，返回满足条件的元素个数，即划分点的索引
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let mut a: Link<isize> = link![1, 2, 3, 4, 5, 6];
+    /// let k = a.partition_in_place(|v| v % 2 == 0);
+    /// assert_eq!(k, 3);
+    /// assert_eq!(format!("{:?}", a), "[2, 4, 6, 1, 3, 5]");
+    /// ```
+    pub fn partition_in_place<F>(&mut self, mut pred: F) -> usize
+    where
+        F: FnMut(&T) -> bool {
+        let mut rest = self.take();
+        let mut no = Link::new();
+        let mut yes_tail = &mut *self;
+        let mut no_tail = &mut no;
+        let mut count = 0;
+        while let Some(mut node) = rest.0.take() {
+            rest = node.next.take();
+            if pred(&node.value) {
+                *yes_tail = Link(Some(node));
+                yes_tail = &mut yes_tail.0.as_mut().unwrap().next;
+                count += 1;
+            } else {
+                *no_tail = Link(Some(node));
+                no_tail = &mut no_tail.0.as_mut().unwrap().next;
+            }
+        }
+        *yes_tail = no;
+        count
+    }
+    /// 按分类闭包的返回值把元素分到n个链表中，只修改结点的链接，各链表内保持原有顺序，
+    /// 是partition_in_place的多路版本
+    /// # Panics
+    /// 分类闭包返回的值不小于n时恐慌
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let l: Link<usize> = (0..10).collect();
+    /// let shards = l.demux(|v| v % 3, 3);
+    /// assert_eq!(format!("{:?}", shards), "[[0, 3, 6, 9], [1, 4, 7], [2, 5, 8]]");
+    /// ```
+    pub fn demux<F>(mut self, mut classify: F, n: usize) -> Vec<Self>
+    where
+        F: FnMut(&T) -> usize {
+        // 先倒序压入各链表的头部，最后统一反转
+        let mut buckets: Vec<Self> = (0..n).map(|_| Link::new()).collect();
+        while let Some(mut node) = self.0.take() {
+            self = node.next.take();
+            let i = classify(&node.value);
+            let bucket = match buckets.get_mut(i) {
+                Some(b) => b,
+                None => panic!("bucket index {} out of range for {} buckets", i, n),
+            };
+            node.next = bucket.take();
+            bucket.0 = Some(node);
+        }
+        for b in buckets.iter_mut() {
+            b.reverse();
+        }
+        buckets
+    }
+    /// 与first_duplicate相同，但只要求元素实现PartialEq，逐对比较，O(n²)
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let l: Link<f64> = link![0.5, 1.0, 0.5];
+    /// assert_eq!(l.first_duplicate_by_eq(), Some((0, 2)));
+    /// ```
+    pub fn first_duplicate_by_eq(&self) -> Option<(usize, usize)>
+    where
+        T: PartialEq {
+        self.iter().enumerate().find_map(|(j, v)| {
+            self.iter().take(j).position(|u| u == v).map(|i| (i, j))
+        })
+    }
+    /// 移出并返回满足条件的最长前缀，剩余部分留在链表中，只在分界处断开链接
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let mut tokens: Link<&str> = link![" ", " ", "let", " ", "x"];
+    /// let ws = tokens.take_while_split(|t| t.trim().is_empty());
+    /// assert_eq!(ws.len(), 2);
+    /// assert_eq!(format!("{:?}", tokens), r#"["let", " ", "x"]"#);
+    /// ```
+    pub fn take_while_split<F>(&mut self, mut pred: F) -> Self
+    where
+        F: FnMut(&T) -> bool {
+        let mut slot = &mut *self;
+        while slot.0.as_ref().is_some_and(|n| pred(&n.value)) {
+            slot = &mut slot.0.as_mut().unwrap().next;
+        }
+        let rest = slot.take();
+        core::mem::replace(self, rest)
+    }
+    /// 删除满足条件的最长前缀，返回删除的元素个数
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let mut l: Link<isize> = link![0, 0, 1, 0];
+    /// assert_eq!(l.drop_while(|v| *v == 0), 2);
+    /// assert_eq!(format!("{:?}", l), "[1, 0]");
+    /// ```
+    pub fn drop_while<F>(&mut self, mut pred: F) -> usize
+    where
+        F: FnMut(&T) -> bool {
+        let mut dropped = 0;
+        while self.0.as_ref().is_some_and(|n| pred(&n.value)) {
+            self.pop();
+            dropped += 1;
+        }
+        dropped
+    }
+    /// 两个链表从头开始相等的元素个数
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let a: Link<u8> = link![1, 2, 3, 4];
+    /// let b: Link<u8> = link![1, 2, 5];
+    /// assert_eq!(a.common_prefix_len(&b), 2);
+    /// assert_eq!(a.common_prefix_len(&Link::new()), 0);
+    /// ```
+    pub fn common_prefix_len(&self, other: &Self) -> usize
+    where
+        T: PartialEq {
+        self.iter().zip(other).take_while(|(a, b)| a == b).count()
+    }
+    /// 以prefix开头时去掉该前缀并返回剩余部分，否则返回None
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let frame: Link<u8> = link![0xAA, 0x55, 7, 8];
+    /// let header: Link<u8> = link![0xAA, 0x55];
+    /// let body = frame.strip_prefix(&header).unwrap();
+    /// assert_eq!(format!("{:?}", body), "[7, 8]");
+    /// assert!(body.strip_prefix(&header).is_none());
+    /// ```
+    pub fn strip_prefix(mut self, prefix: &Self) -> Option<Self>
+    where
+        T: PartialEq {
+        for p in prefix {
+            match self.pop() {
+                Some(v) if v == *p => {},
+                _ => return None,
+            }
+        }
+        Some(self)
+    }
+    /// strip_prefix的借用版本，返回剩余部分的引用，不修改链表
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let l: Link<char> = link!['G', 'E', 'T', ' ', '/'];
+    /// let rest = l.strip_prefix_ref(&link!['G', 'E', 'T']).unwrap();
+    /// assert_eq!(format!("{:?}", rest), "[' ', '/']");
+    /// assert!(l.strip_prefix_ref(&link!['P']).is_none());
+    /// assert_eq!(l.len(), 5);
+    /// ```
+    pub fn strip_prefix_ref(&self, prefix: &Self) -> Option<&Self>
+    where
+        T: PartialEq {
+        let mut rest = self;
+        for p in prefix {
+            match rest.0.as_deref() {
+                Some(n) if n.value == *p => rest = &n.next,
+                _ => return None,
+            }
+        }
+        Some(rest)
+    }
+    /// 在链表的尾部追加元素，需要遍历整个链表，O(n)；
+    /// 先进先出的场景可以使用尾部入队为O(1)的ring::RingQueue
     pub fn push_back(&mut self, val: T) {
+        trace_op!("push_back", self);
         let n = Node::new(val, None);
         match self.0.as_mut() {
             //空链表
@@ -234,7 +914,8 @@ impl<T> Link<T> {
             },
         }
     }
-    /// 弹出最后第一个元素，当链表为空时返回None
+    /// 弹出最后第一个元素，当链表为空时返回None。
+    /// 单向链表没有尾指针，每次都要遍历整个链表，O(n)，反复从尾部弹出总共为O(n²)
     /// # 例子
     /// ```
     /// use link::*;
@@ -247,12 +928,15 @@ impl<T> Link<T> {
         // 不利用长度的实现
         let node = &mut self.0;
         if node.as_ref()?.next.0.is_none() {
+            count_op!(MOVES);
             return Some(node.take()?.value)
         }
         let mut node = node.as_mut()?;
         while node.next.0.as_ref().and_then(|s| s.next.0.as_ref()).is_some() {
             node = node.next.0.as_mut()?;
+            count_op!(STEPS);
         }
+        count_op!(MOVES);
         Some(node.next.0.take()?.value)
 
         // 利用长度的实现
@@ -275,7 +959,8 @@ impl<T> Link<T> {
     /// l.push(-1);
     /// assert_eq!(format!("{:?}", l), "[-1, 0, 1, 2]");
     /// ```
-    pub fn push(&mut self, val: T) {           
+    pub fn push(&mut self, val: T) {
+        trace_op!("push", self);
         let n = Node::new(val, self.0.take());
         self.0 = Some(Box::new(n));
     }
@@ -291,6 +976,7 @@ impl<T> Link<T> {
     pub fn pop(&mut self) -> Option<T> {
         let node = self.0.take()?;
         *self = node.next;
+        count_op!(MOVES);
         Some(node.value)
     }
     /// 获取链表的第一个元素的不可变引用，当链表为空时返回None
@@ -329,6 +1015,103 @@ impl<T> Link<T> {
         }
         Some(&mut p.value)
     }
+    /// 获取第i个元素的不可变引用，索引超出范围时返回None，是Index的不恐慌版本
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let l: Link<isize> = link![0, 1, 2];
+    /// assert_eq!(l.at(1), Some(&1));
+    /// assert_eq!(l.at(3), None);
+    /// ```
+    pub fn at(&self, i: usize) -> Option<&T> {
+        Some(&self.get(i)?.value)
+    }
+    /// 获取第i个元素的可变引用，索引超出范围时返回None，是IndexMut的不恐慌版本
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let mut l: Link<isize> = link![0, 1, 2];
+    /// if let Some(v) = l.at_mut(2) {
+    ///     *v = 5;
+    /// }
+    /// assert_eq!(l.at_mut(3), None);
+    /// assert_eq!(format!("{:?}", l), "[0, 1, 5]");
+    /// ```
+    pub fn at_mut(&mut self, i: usize) -> Option<&mut T> {
+        Some(&mut self.get_mut(i)?.value)
+    }
+    /// 获取第一个节点的不可变引用，当链表为空时返回None
+    pub fn first_node(&self) -> Option<&Node<T>> {
+        self.0.as_deref()
+    }
+    /// 获取最后一个节点的不可变引用，当链表为空时返回None
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let l: Link<isize> = link![0, 1, 2];
+    /// assert_eq!(l.first_node().unwrap().value, 0);
+    /// assert!(l.last_node().unwrap().next().is_none());
+    /// ```
+    pub fn last_node(&self) -> Option<&Node<T>> {
+        let mut p = self.0.as_deref()?;
+        while let Some(t) = p.next.0.as_deref() {
+            p = t;
+        }
+        Some(p)
+    }
+    /// 把链表分解为第一个元素和其余部分，当链表为空时返回None
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// fn sum(l: Link<isize>) -> isize {
+    ///     match l.head_tail() {
+    ///         Some((x, rest)) => x + sum(rest),
+    ///         None => 0,
+    ///     }
+    /// }
+    /// assert_eq!(sum(link![1, 2, 3]), 6);
+    /// ```
+    pub fn head_tail(mut self) -> Option<(T, Self)> {
+        let node = self.0.take()?;
+        let Node {value, next} = *node;
+        Some((value, next))
+    }
+    /// 借用第一个元素和其余部分，当链表为空时返回None
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// fn max(l: &Link<isize>) -> Option<&isize> {
+    ///     let (x, rest) = l.head_tail_ref()?;
+    ///     Some(match max(rest) {
+    ///         Some(m) if m > x => m,
+    ///         _ => x,
+    ///     })
+    /// }
+    /// assert_eq!(max(&link![3, 7, 2]), Some(&7));
+    /// ```
+    pub fn head_tail_ref(&self) -> Option<(&T, &Self)> {
+        let node = self.0.as_ref()?;
+        Some((&node.value, &node.next))
+    }
+    /// 可变借用第一个元素和其余部分，当链表为空时返回None
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// fn prefix_sum(l: &mut Link<isize>, acc: isize) {
+    ///     if let Some((x, rest)) = l.head_tail_mut() {
+    ///         *x += acc;
+    ///         let x = *x;
+    ///         prefix_sum(rest, x);
+    ///     }
+    /// }
+    /// let mut l: Link<isize> = link![1, 2, 3];
+    /// prefix_sum(&mut l, 0);
+    /// assert_eq!(format!("{:?}", l), "[1, 3, 6]");
+    /// ```
+    pub fn head_tail_mut(&mut self) -> Option<(&mut T, &mut Self)> {
+        let node = self.0.as_mut()?;
+        Some((&mut node.value, &mut node.next))
+    }
     /// 在指定位置插入元素，返回被插入元素的不可变引用，当插入失败时返回None
     /// # 例子
     /// ```
@@ -339,6 +1122,7 @@ impl<T> Link<T> {
     /// assert_eq!(format!("{:?}", l), "[0, 1, 3, 2]");
     /// ```
     pub fn insert(&mut self, i: usize, val: T) -> Option<&T> {
+        trace_op!("insert", self, i);
         if i == 0 {
             self.push(val);
             self.front()
@@ -359,15 +1143,72 @@ impl<T> Link<T> {
     /// assert_eq!(format!("{:?}", l), "[0, 2]");
     /// ```
     pub fn delete(&mut self, i: usize) -> Option<T> {
+        trace_op!("delete", self, i);
         if i == 0 {
             self.pop()
         } else {
             let node = self.get_mut(i-1)?;
             let n = node.next.0.take()?;
             node.next = n.next;
+            count_op!(MOVES);
             Some(n.value)
         }
     }
+    /// 只保留满足条件的元素，单次遍历，直接修改链接删除其余结点
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let mut l: Link<isize> = link![1, 2, 3, 4, 5, 6];
+    /// l.retain(|v| v % 3 != 0);
+    /// assert_eq!(format!("{:?}", l), "[1, 2, 4, 5]");
+    /// ```
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool {
+        self.retain_mut(|v| f(v));
+    }
+    /// 只保留满足条件的元素，条件闭包可以修改元素，单次遍历完成
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let mut l: Link<isize> = link![1, 2, 3, 4];
+    /// l.retain_mut(|v| {
+    ///     *v *= 2;
+    ///     *v > 4
+    /// });
+    /// assert_eq!(format!("{:?}", l), "[6, 8]");
+    /// ```
+    pub fn retain_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T) -> bool {
+        self.retain_enumerate_mut(|_, v| f(v));
+    }
+    /// 只保留满足条件的元素，条件闭包可以修改元素，并得到元素的原始索引，单次遍历完成
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let mut l: Link<isize> = link![1, 2, 3, 4, 5];
+    /// l.retain_enumerate_mut(|i, v| {
+    ///     *v *= 10;
+    ///     i % 2 == 0
+    /// });
+    /// assert_eq!(format!("{:?}", l), "[10, 30, 50]");
+    /// ```
+    pub fn retain_enumerate_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(usize, &mut T) -> bool {
+        let mut i = 0;
+        let mut node = self;
+        while let Some(n) = node.0.as_mut() {
+            if f(i, &mut n.value) {
+                node = &mut node.0.as_mut().unwrap().next;
+            } else {
+                let n = node.0.take().unwrap();
+                *node = n.next;
+            }
+            i += 1;
+        }
+    }
     /// 生成不可变迭代器
     /// # 例子
     /// ```
@@ -380,7 +1221,7 @@ impl<T> Link<T> {
     /// assert_eq!(iter.next(), None);
     /// ```
     pub fn iter(&self) -> Iter<'_, T> {
-        Iter {data: self.0.as_ref()}
+        Iter {data: self, index: 0, end: None, back: Vec::new()}
     }
     /// 生成可变迭代器
     /// # 例子
@@ -394,16 +1235,571 @@ impl<T> Link<T> {
     /// assert_eq!(iter.next(), None);
     /// ```
     pub fn iter_mut(&mut self) -> IterMut<'_, T> {
-        IterMut {data: self.0.as_mut()}
+        IterMut {data: Some(self), index: 0, back: None}
     }
-    /// 用重复的元素创建链表
+    /// 移出全部元素的迭代器，调用后链表立即为空并可以继续使用，
+    /// 即使迭代器被泄漏也是如此，未取出的元素在迭代器析构时释放
     /// # 例子
     /// ```
     /// use link::*;
-    /// let a: Link<isize> = Link::from_elem(-1, 3);
-    /// assert_eq!(format!("{:?}", a), "[-1, -1, -1]");
+    /// let mut l: Link<isize> = link![0, 1, 2, 3];
+    /// let mut d = l.drain_all();
+    /// assert_eq!(d.next(), Some(0));
+    /// assert_eq!(d.next_back(), Some(3));
+    /// drop(d);
+    /// assert!(l.is_empty());
+    /// l.push(9);
+    /// std::mem::forget(l.drain_all());
+    /// assert!(l.is_empty());
     /// ```
-    pub fn from_elem(val: T, n: usize) -> Self 
+    pub fn drain_all(&mut self) -> DrainAll<'_, T> {
+        DrainAll {data: self.take().into_iter(), marker: core::marker::PhantomData}
+    }
+    /// 移出range范围内元素的迭代器，与Vec::drain相同，元素在迭代时逐个摘下，
+    /// 迭代器析构时删除范围内剩余的元素，范围前后的部分重新连接
+    /// # Panics
+    /// 当范围的起点大于终点或终点超出链表长度时恐慌
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let mut l: Link<isize> = link![0, 1, 2, 3, 4, 5];
+    /// let taken: Vec<isize> = l.drain(1..3).collect();
+    /// assert_eq!(taken, vec![1, 2]);
+    /// assert_eq!(format!("{:?}", l), "[0, 3, 4, 5]");
+    /// let mut d = l.drain(2..);
+    /// assert_eq!(d.next(), Some(4));
+    /// drop(d);
+    /// assert_eq!(format!("{:?}", l), "[0, 3]");
+    /// ```
+    pub fn drain<R>(&mut self, range: R) -> Drain<'_, T>
+    where
+        R: core::ops::RangeBounds<usize> {
+        use core::ops::Bound;
+        let len = self.len();
+        let start = match range.start_bound() {
+            Bound::Included(&i) => i,
+            Bound::Excluded(&i) => i.checked_add(1).expect("range start overflows usize"),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&i) => i.checked_add(1).expect("range end overflows usize"),
+            Bound::Excluded(&i) => i,
+            Bound::Unbounded => len,
+        };
+        assert!(start <= end, "drain range starts at {} but ends at {}", start, end);
+        if end > len {
+            Self::out_of_range(end, len);
+        }
+        trace_op!("drain", self, start);
+        let mut slot = self;
+        for _ in 0..start {
+            slot = &mut slot.0.as_mut().unwrap().next;
+            count_op!(STEPS);
+        }
+        Drain {slot, remaining: end - start}
+    }
+    /// 从尾到头消耗链表的元素迭代器，先反转再遍历，总共O(n)
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let l: Link<isize> = link![0, 1, 2];
+    /// let v: Vec<isize> = l.into_iter_rev().collect();
+    /// assert_eq!(v, vec![2, 1, 0]);
+    /// ```
+    pub fn into_iter_rev(mut self) -> IntoIter<T> {
+        self.reverse();
+        self.into_iter()
+    }
+    /// 生成无限循环的不可变迭代器，空链表不产生任何元素
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let l: Link<isize> = link![0, 1, 2];
+    /// let v: Vec<&isize> = l.iter_cycle().take(5).collect();
+    /// assert_eq!(v, vec![&0, &1, &2, &0, &1]);
+    /// ```
+    pub fn iter_cycle(&self) -> iter::Cycle<Iter<'_, T>> {
+        self.iter().cycle()
+    }
+    /// 生成循环k遍的不可变迭代器，元素总数超出usize的范围时取usize::MAX
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let l: Link<isize> = link![0, 1];
+    /// let v: Vec<&isize> = l.iter_cycle_n(2).collect();
+    /// assert_eq!(v, vec![&0, &1, &0, &1]);
+    /// ```
+    pub fn iter_cycle_n(&self, k: usize) -> iter::Take<iter::Cycle<Iter<'_, T>>> {
+        self.iter_cycle().take(self.len().saturating_mul(k))
+    }
+    /// 按位置生成所有有序对(a, b)，a与b取自不同的位置，共n(n-1)对，按a的位置、再按b的位置排列
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let l: Link<char> = link!['x', 'y', 'z'];
+    /// let p: Vec<String> = l.pairs().map(|(a, b)| format!("{}{}", a, b)).collect();
+    /// assert_eq!(p, vec!["xy", "xz", "yx", "yz", "zx", "zy"]);
+    /// ```
+    pub fn pairs(&self) -> Pairs<'_, T> {
+        let mut outer = self.iter().enumerate();
+        Pairs {data: self, cur: outer.next(), outer, inner: self.iter().enumerate()}
+    }
+    /// 两个链表的笛卡尔积，按self的顺序、再按other的顺序排列
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let a: Link<u8> = link![1, 2];
+    /// let b: Link<char> = link!['a', 'b'];
+    /// assert_eq!(format!("{:?}", a.cartesian_product(&b)), "[(1, 'a'), (1, 'b'), (2, 'a'), (2, 'b')]");
+    /// ```
+    pub fn cartesian_product<U>(&self, other: &Link<U>) -> Link<(T, U)>
+    where
+        T: Clone,
+        U: Clone {
+        self.iter().flat_map(|a| other.iter().map(move |b| (a.clone(), b.clone()))).collect()
+    }
+    /// 生成结点级的可变借出迭代器，每次返回一个可以修改当前结点值、
+    /// 在其后插入结点或删除该结点的句柄，插入的结点不会被迭代
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let mut l: Link<isize> = link![1, 2, 3, 4];
+    /// let mut nodes = l.iter_nodes_mut();
+    /// while let Some(mut node) = nodes.next() {
+    ///     if *node.value() % 2 == 0 {
+    ///         node.remove();
+    ///     } else {
+    ///         *node.value_mut() *= 10;
+    ///         node.insert_after(0);
+    ///     }
+    /// }
+    /// assert_eq!(format!("{:?}", l), "[10, 0, 30, 0]");
+    /// ```
+    pub fn iter_nodes_mut(&mut self) -> IterNodesMut<'_, T> {
+        IterNodesMut {data: Some(self), step: 0}
+    }
+    /// 生成重叠窗口的借出迭代器，每个窗口包含一个元素的可变引用和其后n-1个元素的不可变引用
+    /// # Panics
+    /// 当n为0时恐慌
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let mut l: Link<isize> = link![1, 2, 3, 4];
+    /// let mut w = l.windows_mut(2);
+    /// while let Some((x, rest)) = w.next() {
+    ///     *x += rest.sum::<isize>();
+    /// }
+    /// assert_eq!(format!("{:?}", l), "[3, 5, 7, 4]");
+    /// ```
+    pub fn windows_mut(&mut self, n: usize) -> WindowsMut<'_, T> {
+        assert!(n != 0, "window size must be non-zero");
+        WindowsMut {data: Some(self), size: n, started: false}
+    }
+    /// 对每个长度为n的重叠窗口调用闭包，得到的结果组成新链表，
+    /// 元素少于n个时返回空链表
+    /// # Panics
+    /// 当n为0时恐慌
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let l: Link<isize> = link![1, 2, 3, 4];
+    /// let r: Link<Vec<isize>> = l.windowed_map(3, |w| w.cloned().collect());
+    /// assert_eq!(format!("{:?}", r), "[[1, 2, 3], [2, 3, 4]]");
+    /// ```
+    pub fn windowed_map<U, F>(&self, n: usize, mut f: F) -> Link<U>
+    where
+        F: FnMut(iter::Take<Iter<'_, T>>) -> U {
+        assert!(n != 0, "window size must be non-zero");
+        let mut builder = LinkBuilder::new();
+        let mut lead = self.iter().skip(n - 1);
+        let mut start = self;
+        while lead.next().is_some() {
+            let node = start.0.as_ref().unwrap();
+            builder.push(f(start.iter().take(n)));
+            start = &node.next;
+        }
+        builder.finish()
+    }
+    /// 长度为n的滑动窗口之和，一次遍历，每步加上进入窗口的元素并减去离开的元素
+    /// # Panics
+    /// 当n为0时恐慌
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let l: Link<isize> = link![1, 2, 3, 4, 5];
+    /// assert_eq!(format!("{:?}", l.rolling_sum(2)), "[3, 5, 7, 9]");
+    /// assert_eq!(format!("{:?}", l.rolling_sum(6)), "[]");
+    /// ```
+    pub fn rolling_sum(&self, n: usize) -> Self
+    where
+        T: Clone + ops::Add<Output = T> + ops::Sub<Output = T> {
+        assert!(n != 0, "window size must be non-zero");
+        let mut builder = LinkBuilder::new();
+        let mut lead = self.iter();
+        let mut sum = match lead.next() {
+            Some(v) => v.clone(),
+            None => return Link::new(),
+        };
+        for _ in 1..n {
+            match lead.next() {
+                Some(v) => sum = sum + v.clone(),
+                None => return Link::new(),
+            }
+        }
+        builder.push(sum.clone());
+        for (new, old) in lead.zip(self.iter()) {
+            sum = sum + new.clone() - old.clone();
+            builder.push(sum.clone());
+        }
+        builder.finish()
+    }
+    /// 长度为n的滑动窗口的最大值，一次遍历，内部用单调队列保存窗口中可能成为最大值的元素，
+    /// 相等时取靠后的元素
+    /// # Panics
+    /// 当n为0时恐慌
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let l: Link<isize> = link![1, 3, -1, -3, 5, 3, 6, 7];
+    /// assert_eq!(format!("{:?}", l.rolling_max(3)), "[3, 3, 5, 5, 6, 7]");
+    /// ```
+    pub fn rolling_max(&self, n: usize) -> Self
+    where
+        T: Ord + Clone {
+        assert!(n != 0, "window size must be non-zero");
+        let mut builder = LinkBuilder::new();
+        let mut deque: alloc::collections::VecDeque<(usize, &T)> = alloc::collections::VecDeque::new();
+        for (i, v) in self.iter().enumerate() {
+            while deque.back().is_some_and(|&(_, b)| b <= v) {
+                deque.pop_back();
+            }
+            deque.push_back((i, v));
+            if deque[0].0 + n <= i {
+                deque.pop_front();
+            }
+            if i + 1 >= n {
+                builder.push(deque[0].1.clone());
+            }
+        }
+        builder.finish()
+    }
+    /// 合并连续相等的元素，保留每段的第一个元素并记录该段的长度，
+    /// 结果可以用expand_counts还原
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let l: Link<char> = link!['a', 'a', 'b', 'a', 'a', 'a'];
+    /// let runs = l.clone().dedup_with_count();
+    /// assert_eq!(format!("{:?}", runs), "[('a', 2), ('b', 1), ('a', 3)]");
+    /// assert_eq!(format!("{:?}", runs.expand_counts()), format!("{:?}", l));
+    /// ```
+    pub fn dedup_with_count(self) -> Link<(T, usize)>
+    where
+        T: PartialEq {
+        let mut builder = LinkBuilder::new();
+        let mut run: Option<(T, usize)> = None;
+        for v in self {
+            run = match run {
+                Some((r, n)) if r == v => Some((r, n + 1)),
+                Some(prev) => {
+                    builder.push(prev);
+                    Some((v, 1))
+                },
+                None => Some((v, 1)),
+            };
+        }
+        builder.extend(run);
+        builder.finish()
+    }
+    /// 用自定义的相等函数逐个比较两个链表，长度不同时返回false，两个链表的元素类型可以不同
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let a: Link<f64> = link![1.0, 2.0];
+    /// let b: Link<f64> = link![1.0000001, 1.9999999];
+    /// assert!(a.eq_by(&b, |x, y| (x - y).abs() < 1e-6));
+    /// assert!(!a.eq_by(&link![1.0], |x, y| x == y));
+    /// ```
+    pub fn eq_by<U, F>(&self, other: &Link<U>, mut eq: F) -> bool
+    where
+        F: FnMut(&T, &U) -> bool {
+        let mut a = self.iter();
+        let mut b = other.iter();
+        loop {
+            match (a.next(), b.next()) {
+                (Some(x), Some(y)) => if !eq(x, y) { return false },
+                (None, None) => return true,
+                _ => return false,
+            }
+        }
+    }
+    /// 用自定义的比较函数按字典序比较两个链表，两个链表的元素类型可以不同
+    /// # 例子
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use link::*;
+    /// let a: Link<&str> = link!["a", "B"];
+    /// let b: Link<String> = link!["A".to_string(), "b".to_string(), "c".to_string()];
+    /// let cmp = a.cmp_by(&b, |x, y| x.to_lowercase().cmp(&y.to_lowercase()));
+    /// assert_eq!(cmp, Ordering::Less);
+    /// ```
+    pub fn cmp_by<U, F>(&self, other: &Link<U>, mut cmp: F) -> cmp::Ordering
+    where
+        F: FnMut(&T, &U) -> cmp::Ordering {
+        let mut a = self.iter();
+        let mut b = other.iter();
+        loop {
+            match (a.next(), b.next()) {
+                (Some(x), Some(y)) => match cmp(x, y) {
+                    cmp::Ordering::Equal => (),
+                    ord => return ord,
+                },
+                (None, None) => return cmp::Ordering::Equal,
+                (None, Some(_)) => return cmp::Ordering::Less,
+                (Some(_), None) => return cmp::Ordering::Greater,
+            }
+        }
+    }
+    /// 消耗链表，得到排好序的Vec
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let l: Link<isize> = link![3, 1, 2];
+    /// assert_eq!(l.into_sorted_vec(), vec![1, 2, 3]);
+    /// ```
+    #[must_use]
+    pub fn into_sorted_vec(self) -> Vec<T>
+    where
+        T: Ord {
+        let mut v: Vec<T> = self.into_iter().collect();
+        v.sort();
+        v
+    }
+    /// 返回排好序的新链表，原链表不变
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let l: Link<isize> = link![3, 1, 2];
+    /// assert_eq!(format!("{:?}", l.sorted()), "[1, 2, 3]");
+    /// assert_eq!(format!("{:?}", l), "[3, 1, 2]");
+    /// ```
+    #[must_use = "`sorted` returns a new list and leaves the original unchanged"]
+    pub fn sorted(&self) -> Self
+    where
+        T: Ord + Clone {
+        self.clone().into_sorted_vec().into_iter().collect()
+    }
+    /// 合并两个有序链表，只修改结点的链接，相等时a的元素在前
+    fn merge_by<F>(mut a: Self, mut b: Self, cmp: &mut F) -> Self
+    where
+        F: FnMut(&T, &T) -> cmp::Ordering {
+        let mut link = Link::new();
+        let mut tail = &mut link;
+        loop {
+            let src = match (a.0.as_ref(), b.0.as_ref()) {
+                (Some(x), Some(y)) => if cmp(&y.value, &x.value) == cmp::Ordering::Less { &mut b } else { &mut a },
+                (Some(_), None) => { *tail = a; break; },
+                (None, _) => { *tail = b; break; },
+            };
+            let mut node = src.0.take().unwrap();
+            *src = node.next.take();
+            *tail = Link(Some(node));
+            tail = &mut tail.0.as_mut().unwrap().next;
+        }
+        link
+    }
+    /// 归并两个有序链表并去掉重复元素（包括各自内部和两者之间的重复），一次遍历，
+    /// 只修改结点的链接，相等的元素保留最先遇到的一个
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let a: Link<isize> = link![1, 2, 2, 5, 7];
+    /// let b: Link<isize> = link![2, 3, 5, 8];
+    /// assert_eq!(format!("{:?}", a.merge_dedup(b)), "[1, 2, 3, 5, 7, 8]");
+    /// ```
+    pub fn merge_dedup(mut self, mut other: Self) -> Self
+    where
+        T: Ord {
+        // 倒序压入结果的头部，头部即是最后保留的元素，最后统一反转
+        let mut rev = Link::new();
+        loop {
+            let src = match (self.0.as_ref(), other.0.as_ref()) {
+                (Some(x), Some(y)) => if y.value < x.value { &mut other } else { &mut self },
+                (Some(_), None) => &mut self,
+                (None, Some(_)) => &mut other,
+                (None, None) => break,
+            };
+            let mut node = src.0.take().unwrap();
+            *src = node.next.take();
+            if rev.front() != Some(&node.value) {
+                node.next = rev.take();
+                rev.0 = Some(node);
+            }
+        }
+        rev.reverse();
+        rev
+    }
+    /// 多路归并若干有序链表，两两归并相邻的链表直到只剩一个，只修改结点的链接，
+    /// 共O(n log k)次比较，相等元素按所在链表的先后排列
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let shards: Vec<Link<isize>> = vec![link![1, 4, 7], link![2, 5], Link::new(), link![0, 3, 6, 9]];
+    /// let l = Link::merge_k(shards);
+    /// assert_eq!(format!("{:?}", l), "[0, 1, 2, 3, 4, 5, 6, 7, 9]");
+    /// ```
+    pub fn merge_k<I>(lists: I) -> Self
+    where
+        I: IntoIterator<Item = Self>,
+        T: Ord {
+        let mut lists: Vec<Self> = lists.into_iter().collect();
+        while lists.len() > 1 {
+            let mut merged = Vec::with_capacity(lists.len().div_ceil(2));
+            let mut rest = lists.into_iter();
+            while let Some(a) = rest.next() {
+                merged.push(match rest.next() {
+                    Some(b) => Self::merge_by(a, b, &mut T::cmp),
+                    None => a,
+                });
+            }
+            lists = merged;
+        }
+        lists.pop().unwrap_or_default()
+    }
+    /// 把元素克隆为不可变的共享切片，克隆快照只增加引用计数，
+    /// 可以交给其他线程读取，之后对链表的修改不影响快照
+    /// # 例子
+    /// ```
+    /// use std::sync::{Arc, Mutex};
+    /// use link::*;
+    /// let shared = Mutex::new(link![1, 2, 3]);
+    /// let snap = shared.lock().unwrap().snapshot();
+    /// shared.lock().unwrap().push(0);
+    /// assert_eq!(&*snap, &[1, 2, 3]);
+    /// let reader = Arc::clone(&snap);
+    /// assert_eq!(std::thread::spawn(move || reader.iter().sum::<i32>()).join().unwrap(), 6);
+    /// ```
+    pub fn snapshot(&self) -> alloc::sync::Arc<[T]>
+    where
+        T: Clone {
+        self.iter().cloned().collect()
+    }
+    /// 把元素依次克隆到切片中，长度不一致时返回错误且不修改切片
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let l: Link<isize> = link![1, 2, 3];
+    /// let mut buf = [0; 3];
+    /// l.copy_to_slice(&mut buf).unwrap();
+    /// assert_eq!(buf, [1, 2, 3]);
+    /// assert_eq!(l.copy_to_slice(&mut [0; 2]), Err(LengthMismatch {expected: 3, found: 2}));
+    /// ```
+    pub fn copy_to_slice(&self, dst: &mut [T]) -> Result<(), LengthMismatch>
+    where
+        T: Clone {
+        let len = self.len();
+        if len != dst.len() {
+            return Err(LengthMismatch {expected: len, found: dst.len()});
+        }
+        for (d, v) in dst.iter_mut().zip(self.iter()) {
+            d.clone_from(v);
+        }
+        Ok(())
+    }
+    /// 用切片的元素依次覆盖链表的元素，长度不一致时返回错误且不修改链表
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let mut l: Link<isize> = link![0, 0];
+    /// l.copy_from_slice(&[4, 5]).unwrap();
+    /// assert_eq!(format!("{:?}", l), "[4, 5]");
+    /// assert!(l.copy_from_slice(&[1]).is_err());
+    /// ```
+    pub fn copy_from_slice(&mut self, src: &[T]) -> Result<(), LengthMismatch>
+    where
+        T: Clone {
+        let len = self.len();
+        if len != src.len() {
+            return Err(LengthMismatch {expected: len, found: src.len()});
+        }
+        for (v, s) in self.iter_mut().zip(src) {
+            v.clone_from(s);
+        }
+        Ok(())
+    }
+    /// 把元素复制到定长数组，长度不等于N时返回None
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let l: Link<isize> = link![1, 2, 3];
+    /// assert_eq!(l.to_array::<3>(), Some([1, 2, 3]));
+    /// assert_eq!(l.to_array::<2>(), None);
+    /// ```
+    pub fn to_array<const N: usize>(&self) -> Option<[T; N]>
+    where
+        T: Clone {
+        if self.len() != N {
+            return None;
+        }
+        <[T; N]>::try_from(self.iter().cloned().collect::<Vec<T>>()).ok()
+    }
+    /// 用value的克隆覆盖所有元素，不改变链表结构
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let mut l: Link<isize> = link![1, 2, 3];
+    /// l.fill(0);
+    /// assert_eq!(format!("{:?}", l), "[0, 0, 0]");
+    /// ```
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone {
+        self.fill_with(|| value.clone());
+    }
+    /// 用闭包的返回值依次覆盖所有元素，不改变链表结构
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let mut l: Link<isize> = link![0, 0, 0];
+    /// let mut n = 0;
+    /// l.fill_with(|| { n += 1; n });
+    /// assert_eq!(format!("{:?}", l), "[1, 2, 3]");
+    /// ```
+    pub fn fill_with<F>(&mut self, mut f: F)
+    where
+        F: FnMut() -> T {
+        for v in self.iter_mut() {
+            *v = f();
+        }
+    }
+    /// 用分隔符连接所有字符串元素，预先计算容量，只分配一次
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let l: Link<&str> = link!["a", "b", "c"];
+    /// assert_eq!(l.join(", "), "a, b, c");
+    /// ```
+    pub fn join(&self, sep: &str) -> String
+    where
+        T: AsRef<str> {
+        let (len, n): (usize, usize) = self.iter().fold((0, 0), |(len, n), s| (len + s.as_ref().len(), n + 1));
+        let mut s = String::with_capacity(len + sep.len() * n.saturating_sub(1));
+        for (i, v) in self.iter().enumerate() {
+            if i > 0 {
+                s.push_str(sep);
+            }
+            s.push_str(v.as_ref());
+        }
+        s
+    }
+    /// 用重复的元素创建链表
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let a: Link<isize> = Link::from_elem(-1, 3);
+    /// assert_eq!(format!("{:?}", a), "[-1, -1, -1]");
+    /// ```
+    pub fn from_elem(val: T, n: usize) -> Self 
     where
         T: Clone {
         let mut link: Link<T> = Self::new();
@@ -413,19 +1809,315 @@ impl<T> Link<T> {
         link
     }
 }
-use std::iter;
+#[cfg(feature = "std")]
+impl<T> Link<T> {
+    /// 按键去重，只保留每个键第一次出现的元素，元素不需要相邻，保持原有顺序
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let mut users: Link<(u32, &str)> = link![(1, "ann"), (2, "bob"), (1, "ann2"), (3, "cat"), (2, "bob2")];
+    /// users.unique_by(|u| u.0);
+    /// assert_eq!(format!("{:?}", users), r#"[(1, "ann"), (2, "bob"), (3, "cat")]"#);
+    /// ```
+    pub fn unique_by<K, F>(&mut self, key: F)
+    where
+        K: core::hash::Hash + Eq,
+        F: FnMut(&T) -> K {
+        self.unique_by_with_hasher(key, std::collections::hash_map::RandomState::new());
+    }
+    /// 与unique_by相同，使用指定的哈希器
+    /// # 例子
+    /// ```
+    /// use std::hash::BuildHasherDefault;
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use link::*;
+    /// let mut l: Link<&str> = link!["a", "B", "A", "b", "c"];
+    /// l.unique_by_with_hasher(|s| s.to_lowercase(), BuildHasherDefault::<DefaultHasher>::default());
+    /// assert_eq!(format!("{:?}", l), r#"["a", "B", "c"]"#);
+    /// ```
+    pub fn unique_by_with_hasher<K, F, S>(&mut self, mut key: F, hasher: S)
+    where
+        K: core::hash::Hash + Eq,
+        F: FnMut(&T) -> K,
+        S: core::hash::BuildHasher {
+        let mut seen = std::collections::HashSet::with_hasher(hasher);
+        let mut node = self;
+        while let Some(n) = node.0.as_ref() {
+            if seen.insert(key(&n.value)) {
+                node = &mut node.0.as_mut().unwrap().next;
+            } else {
+                let n = node.0.take().unwrap();
+                *node = n.next;
+            }
+        }
+    }
+}
+#[cfg(feature = "std")]
+impl<T: core::hash::Hash + Eq> Link<T> {
+    /// 查找第一对重复元素，返回(第一次出现的索引, 重复出现的索引)，
+    /// 其中重复出现的索引是所有重复中最小的，基于哈希表，O(n)
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let l: Link<char> = link!['a', 'b', 'c', 'b', 'a'];
+    /// assert_eq!(l.first_duplicate(), Some((1, 3)));
+    /// assert!(l.has_duplicates());
+    /// assert!(!link!['a', 'b'].has_duplicates());
+    /// ```
+    pub fn first_duplicate(&self) -> Option<(usize, usize)> {
+        let mut seen = std::collections::HashMap::new();
+        for (j, v) in self.iter().enumerate() {
+            if let Some(&i) = seen.get(v) {
+                return Some((i, j));
+            }
+            seen.insert(v, j);
+        }
+        None
+    }
+    /// 判断是否存在重复元素，基于哈希表，O(n)
+    pub fn has_duplicates(&self) -> bool {
+        self.first_duplicate().is_some()
+    }
+    /// 统计每个元素出现的次数
+    fn counts(&self) -> std::collections::HashMap<&T, usize> {
+        let mut counts = std::collections::HashMap::new();
+        for v in self {
+            *counts.entry(v).or_insert(0) += 1;
+        }
+        counts
+    }
+    /// 按多重集合判断self是否包含于other，即每个元素在self中的出现次数都不超过在other中的次数
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let a: Link<char> = link!['a', 'b', 'a'];
+    /// let b: Link<char> = link!['b', 'a', 'c', 'a'];
+    /// assert!(a.is_subset(&b));
+    /// assert!(b.is_superset(&a));
+    /// assert!(!link!['a', 'a', 'a'].is_subset(&b));
+    /// ```
+    pub fn is_subset(&self, other: &Self) -> bool {
+        let theirs = other.counts();
+        self.counts().into_iter().all(|(v, n)| theirs.get(v).is_some_and(|&m| n <= m))
+    }
+    /// 按多重集合判断self是否包含other
+    pub fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+    /// 多重集合的差：从self中依次去掉other中的每次出现，保持self中的顺序
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let a: Link<isize> = link![1, 2, 2, 3, 2];
+    /// let b: Link<isize> = link![2, 4, 2];
+    /// assert_eq!(format!("{:?}", a.multiset_difference(&b)), "[1, 3, 2]");
+    /// ```
+    pub fn multiset_difference(&self, other: &Self) -> Self
+    where
+        T: Clone {
+        let mut remove = other.counts();
+        self.iter().filter(|v| match remove.get_mut(v) {
+            Some(n) if *n > 0 => {
+                *n -= 1;
+                false
+            },
+            _ => true,
+        }).cloned().collect()
+    }
+    /// 多重集合的交：每个元素保留两边出现次数的较小值，保持self中的顺序
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let a: Link<isize> = link![1, 2, 2, 3, 2];
+    /// let b: Link<isize> = link![2, 4, 2, 1];
+    /// assert_eq!(format!("{:?}", a.multiset_intersection(&b)), "[1, 2, 2]");
+    /// ```
+    pub fn multiset_intersection(&self, other: &Self) -> Self
+    where
+        T: Clone {
+        let mut keep = other.counts();
+        self.iter().filter(|v| match keep.get_mut(v) {
+            Some(n) if *n > 0 => {
+                *n -= 1;
+                true
+            },
+            _ => false,
+        }).cloned().collect()
+    }
+    /// 多重集合的并：每个元素的出现次数取两边的较大值，
+    /// 先是self的全部元素，再按other中的顺序补上多出的部分
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let a: Link<isize> = link![1, 2, 2];
+    /// let b: Link<isize> = link![2, 3, 2, 2];
+    /// assert_eq!(format!("{:?}", a.multiset_union(&b)), "[1, 2, 2, 3, 2]");
+    /// ```
+    pub fn multiset_union(&self, other: &Self) -> Self
+    where
+        T: Clone {
+        let mut union = self.clone();
+        union.concat(other.multiset_difference(self));
+        union
+    }
+    /// 多重集合的对称差：self比other多出的部分，接着是other比self多出的部分
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let a: Link<isize> = link![1, 2, 2, 3];
+    /// let b: Link<isize> = link![2, 3, 4];
+    /// assert_eq!(format!("{:?}", a.symmetric_difference(&b)), "[1, 2, 4]");
+    /// ```
+    pub fn symmetric_difference(&self, other: &Self) -> Self
+    where
+        T: Clone {
+        let mut diff = self.multiset_difference(other);
+        diff.concat(other.multiset_difference(self));
+        diff
+    }
+}
+impl<T: Clone> Link<(T, usize)> {
+    /// 把(元素, 次数)展开为重复的元素，是dedup_with_count的逆操作
+    pub fn expand_counts(self) -> Link<T> {
+        let mut builder = LinkBuilder::new();
+        for (v, n) in self {
+            builder.extend(iter::repeat_n(v, n));
+        }
+        builder.finish()
+    }
+}
+impl<'a> Link<&'a str> {
+    /// 按分隔符切分字符串，得到子串组成的链表
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let l = Link::split_str("a,b,,c", ",");
+    /// assert_eq!(format!("{:?}", l), r#"["a", "b", "", "c"]"#);
+    /// ```
+    pub fn split_str(input: &'a str, pattern: &str) -> Self {
+        input.split(pattern).collect()
+    }
+    /// 按空白切分字符串，忽略连续的空白
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let l = Link::split_whitespace("  let x =\t1 ");
+    /// assert_eq!(format!("{:?}", l), r#"["let", "x", "=", "1"]"#);
+    /// ```
+    pub fn split_whitespace(input: &'a str) -> Self {
+        input.split_whitespace().collect()
+    }
+}
+use core::iter;
+/// 长度不一致的错误，expected为链表长度，found为另一方的长度
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LengthMismatch {
+    pub expected: usize,
+    pub found: usize,
+}
+impl fmt::Display for LengthMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "length mismatch: Link has {} elements, other side has {}", self.expected, self.found)
+    }
+}
+impl core::error::Error for LengthMismatch {}
 /// 不可变引用的迭代器
+#[must_use = "iterators are lazy and do nothing unless consumed"]
 pub struct Iter<'a, T> {
-    data: Option<&'a Box<Node<T>>>
+    data: &'a Link<T>,
+    index: usize,
+    // 第一次调用next_back时记录剩余元素，end为从头部迭代时的终点
+    end: Option<usize>,
+    back: Vec<&'a T>,
+}
+impl<'a, T> Clone for Iter<'a, T> {
+    fn clone(&self) -> Self {
+        Iter {data: self.data, index: self.index, end: self.end, back: self.back.clone()}
+    }
 }
 impl<'a, T> iter::Iterator for Iter<'a, T> {
     type Item = &'a T;
     fn next(& mut self) -> Option<Self::Item> {
-        let node = self.data?;
-        self.data = node.next.0.as_ref();
+        if self.end == Some(self.index) {
+            return None;
+        }
+        let node = self.data.0.as_deref()?;
+        self.data = &node.next;
+        self.index += 1;
         Some(&node.value)
     }
 }
+impl<'a, T> Iter<'a, T> {
+    /// 下一次next将返回的元素的索引，即已经返回的元素个数
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let l: Link<isize> = link![0, 1, 2];
+    /// let mut iter = l.iter();
+    /// iter.next();
+    /// assert_eq!(iter.index(), 1);
+    /// assert_eq!(iter.remaining(), 2);
+    /// ```
+    pub fn index(&self) -> usize {
+        self.index
+    }
+    /// 剩余未返回的元素个数，O(n)
+    pub fn remaining(&self) -> usize {
+        match self.end {
+            Some(end) => end - self.index,
+            None => self.data.len(),
+        }
+    }
+    /// 查看剩余未返回的元素组成的子链表，生命周期与原链表相同，
+    /// 已经通过next_back从尾部返回的元素仍包含在其中
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// // 递归下降地求和，把剩余部分传给下一层
+    /// fn sum(l: &Link<isize>) -> isize {
+    ///     let mut iter = l.iter();
+    ///     match iter.next() {
+    ///         Some(v) => v + sum(iter.as_link()),
+    ///         None => 0,
+    ///     }
+    /// }
+    /// assert_eq!(sum(&link![1, 2, 3]), 6);
+    /// ```
+    pub fn as_link(&self) -> &'a Link<T> {
+        self.data
+    }
+}
+/// 第一次调用next_back时用O(n)的时间和空间记录剩余元素的引用，之后两端都是O(1)
+/// # 例子
+/// ```
+/// use link::*;
+/// let l: Link<isize> = link![0, 1, 2, 3];
+/// assert_eq!(l.iter().rev().collect::<Vec<_>>(), [&3, &2, &1, &0]);
+/// let mut iter = l.iter();
+/// assert_eq!(iter.next_back(), Some(&3));
+/// assert_eq!(iter.next(), Some(&0));
+/// assert_eq!(iter.remaining(), 2);
+/// assert_eq!(iter.next_back(), Some(&2));
+/// assert_eq!(iter.next(), Some(&1));
+/// assert_eq!((iter.next(), iter.next_back()), (None, None));
+/// ```
+impl<'a, T> iter::DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let end = match self.end {
+            Some(end) => end,
+            None => {
+                self.back = self.clone().collect();
+                self.index + self.back.len()
+            },
+        };
+        if end == self.index {
+            self.end = Some(end);
+            return None;
+        }
+        self.end = Some(end - 1);
+        self.back.pop()
+    }
+}
 /// 不可变引用的迭代适配器
 /// # 例子
 /// ```
@@ -446,18 +2138,109 @@ impl<'a, T> iter::IntoIterator for &'a Link<T> {
     }
 }
 /// 可变引用的迭代器
+#[must_use = "iterators are lazy and do nothing unless consumed"]
 pub struct IterMut<'a, T> {
-    data: Option<&'a mut Box<Node<T>>>
+    // 指向当前结点（下一次next将返回的结点）的链接
+    data: Option<&'a mut Link<T>>,
+    index: usize,
+    // 第一次调用next_back时取出的剩余元素，此后data为None
+    back: Option<alloc::collections::VecDeque<&'a mut T>>,
 }
 impl<'a, T> iter::Iterator for IterMut<'a, T> {
     type Item = &'a mut T;
     fn next(& mut self) -> Option<Self::Item> {
-        let node = self.data.take()?;
-        self.data = node.next.0.as_mut();
-        Some(&mut node.value)
+        if let Some(back) = self.back.as_mut() {
+            let v = back.pop_front()?;
+            self.index += 1;
+            return Some(v);
+        }
+        let link = self.data.take()?;
+        if link.0.is_none() {
+            self.data = Some(link);
+            return None;
+        }
+        let Node {value, next} = &mut **link.0.as_mut()?;
+        self.data = Some(next);
+        self.index += 1;
+        Some(value)
+    }
+}
+/// 第一次调用next_back时用O(n)的时间和空间取出剩余元素的引用，之后两端都是O(1)，
+/// 此后依赖当前结点的insert_prev、insert_next等方法按已到达末尾处理
+/// # 例子
+/// ```
+/// use link::*;
+/// let mut l: Link<isize> = link![0, 1, 2];
+/// for (i, v) in l.iter_mut().rev().enumerate() {
+///     *v += 10 * i as isize;
+/// }
+/// assert_eq!(format!("{:?}", l), "[20, 11, 2]");
+/// ```
+impl<'a, T> iter::DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.back.is_none() {
+            let mut back = alloc::collections::VecDeque::new();
+            let mut link = self.data.take();
+            while let Some(Node {value, next}) = link.and_then(|l| l.0.as_deref_mut()) {
+                back.push_back(value);
+                link = Some(next);
+            }
+            self.back = Some(back);
+        }
+        self.back.as_mut()?.pop_back()
     }
 }
 impl<'a, T> IterMut<'a, T> {
+    /// 当前结点的可变引用
+    fn current(&mut self) -> Option<&mut Box<Node<T>>> {
+        self.data.as_mut()?.0.as_mut()
+    }
+    /// 下一次next将返回的元素在链表中的索引，insert_prev插入的元素也计入其中
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let mut l: Link<usize> = link![1,2,3];
+    /// let mut a = l.iter_mut();
+    /// a.next();
+    /// a.insert_prev(4).unwrap();
+    /// a.insert_next(5).unwrap();
+    /// assert_eq!(a.index(), 2);
+    /// assert_eq!(a.remaining(), 3);
+    /// ```
+    pub fn index(&self) -> usize {
+        self.index
+    }
+    /// 剩余未返回的元素个数（包括insert_next插入的元素），O(n)
+    pub fn remaining(&self) -> usize {
+        match &self.back {
+            Some(back) => back.len(),
+            None => self.data.as_ref().map_or(0, |l| l.len()),
+        }
+    }
+    /// 在迭代器当前指向的结点（即下一次next将返回的元素）之前插入结点，
+    /// 也就是紧跟在刚返回的元素之后，插入的元素不会再被迭代。
+    /// 调用过next_back后迭代器不再指向链表中的位置，此时原样返回元素
+    /// ```
+    /// use link::*;
+    /// let mut l: Link<usize> = link![1,2,3];
+    /// let mut a = l.iter_mut();
+    /// a.next();
+    /// a.insert_prev(4).unwrap();
+    /// assert_eq!(a.next(), Some(&mut 2));
+    /// a.next_back();
+    /// assert_eq!(a.insert_prev(5), Err(5));
+    /// assert_eq!(format!("{:?}", l), "[1, 4, 2, 3]");
+    /// ```
+    pub fn insert_prev(&mut self, value: T) -> Result<(), T> {
+        let link = match self.data.take() {
+            Some(link) => link,
+            None => return Err(value),
+        };
+        *link = Node::new(value, link.0.take()).as_link();
+        self.data = link.0.as_mut().map(|n| &mut n.next);
+        self.index += 1;
+        Ok(())
+    }
     /// 迭代器插入结点
     /// ```
     /// use link::*;
@@ -468,7 +2251,7 @@ impl<'a, T> IterMut<'a, T> {
     /// assert_eq!(l, link![1,2,4,3]);
     /// ```
     pub fn insert_next(&mut self, value: T) -> Result<(), &str> {
-        if let Some(node) = &mut self.data {
+        if let Some(node) = self.current() {
             let n = Node::new(value, node.next.0.take());
             node.next = Link::from(Some(Box::new(n)));
             Ok(())
@@ -476,6 +2259,30 @@ impl<'a, T> IterMut<'a, T> {
             Err("The iterator is pointed at no data!")
         }
     }
+    /// 把迭代器的全部元素按顺序插入到当前结点之后，先构建好链再一次性接入，
+    /// 迭代器没有指向结点时不消耗iter并返回错误
+    /// ```
+    /// use link::*;
+    /// let mut l: Link<usize> = link![1,2,3];
+    /// let mut a = l.iter_mut();
+    /// a.next();
+    /// a.splice_next(vec![7, 8, 9]).unwrap();
+    /// assert_eq!(a.next(), Some(&mut 2));
+    /// assert_eq!(a.next(), Some(&mut 7));
+    /// assert_eq!(format!("{:?}", l), "[1, 2, 7, 8, 9, 3]");
+    /// ```
+    pub fn splice_next<I>(&mut self, iter: I) -> Result<(), &str>
+    where
+        I: IntoIterator<Item = T> {
+        if let Some(node) = self.current() {
+            let mut chain: Link<T> = iter.into_iter().collect();
+            chain.concat(node.next.take());
+            node.next = chain;
+            Ok(())
+        } else {
+            Err("The iterator is pointed at no data!")
+        }
+    }
     /// 迭代器删除结点
     /// ```
     /// use link::*;
@@ -486,7 +2293,7 @@ impl<'a, T> IterMut<'a, T> {
     /// assert_eq!(l, link![1,2]);
     /// ```
     pub fn pop_next(&mut self) -> Option<T> {
-        if let Some(node) = &mut self.data {
+        if let Some(node) = self.current() {
             let n = node.next.0.take()?;
             node.next = n.next;
             Some(n.value)
@@ -513,18 +2320,244 @@ impl<'a, T> iter::IntoIterator for &'a mut Link<T> {
         self.iter_mut()
     }
 }
+/// 借出迭代器，每次返回的元素借用迭代器本身，因此前后两次返回的元素可以重叠
+pub trait LendingIterator {
+    /// 元素类型
+    type Item<'b> where Self: 'b;
+    /// 返回下一个元素
+    fn next(&mut self) -> Option<Self::Item<'_>>;
+}
+/// 重叠窗口的借出迭代器
+pub struct WindowsMut<'a, T> {
+    // 指向当前窗口首结点的链接
+    data: Option<&'a mut Link<T>>,
+    size: usize,
+    started: bool,
+}
+impl<'a, T> LendingIterator for WindowsMut<'a, T> {
+    type Item<'b> = (&'b mut T, iter::Take<Iter<'b, T>>) where Self: 'b;
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        if self.started {
+            let link = self.data.take()?;
+            self.data = link.0.as_mut().map(|n| &mut n.next);
+        }
+        self.started = true;
+        let Node {value, next} = &mut **self.data.as_mut()?.0.as_mut()?;
+        let rest: &Link<T> = next;
+        if self.size > 1 && rest.get(self.size - 2).is_none() {
+            return None;
+        }
+        Some((value, rest.iter().take(self.size - 1)))
+    }
+}
+/// 结点级的可变借出迭代器
+pub struct IterNodesMut<'a, T> {
+    // 指向上一次返回的结点的链接
+    data: Option<&'a mut Link<T>>,
+    // 下一次next需要前进的结点数
+    step: usize,
+}
+impl<'a, T> LendingIterator for IterNodesMut<'a, T> {
+    type Item<'b> = NodeMut<'b, T> where Self: 'b;
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        for _ in 0..self.step {
+            let link = self.data.take()?;
+            self.data = link.0.as_mut().map(|n| &mut n.next);
+        }
+        self.step = 1;
+        let link = self.data.as_mut()?;
+        link.0.as_ref()?;
+        Some(NodeMut {link, step: &mut self.step})
+    }
+}
+/// IterNodesMut返回的结点句柄
+pub struct NodeMut<'b, T> {
+    link: &'b mut Link<T>,
+    step: &'b mut usize,
+}
+impl<'b, T> NodeMut<'b, T> {
+    fn node(&mut self) -> &mut Node<T> {
+        self.link.0.as_mut().unwrap()
+    }
+    /// 查看结点的值
+    pub fn value(&self) -> &T {
+        &self.link.0.as_ref().unwrap().value
+    }
+    /// 修改结点的值
+    pub fn value_mut(&mut self) -> &mut T {
+        &mut self.node().value
+    }
+    /// 在当前结点（及之前插入的结点）之后插入结点，多次插入保持调用顺序
+    pub fn insert_after(&mut self, value: T) {
+        let n = *self.step - 1;
+        self.node().skip_mut(n).unwrap().insert_next(value);
+        *self.step += 1;
+    }
+    /// 删除当前结点，返回它的值
+    pub fn remove(self) -> T {
+        let mut node = self.link.0.take().unwrap();
+        *self.link = node.next.take();
+        *self.step -= 1;
+        node.value
+    }
+}
+/// pairs返回的迭代器
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Pairs<'a, T> {
+    data: &'a Link<T>,
+    cur: Option<(usize, &'a T)>,
+    outer: iter::Enumerate<Iter<'a, T>>,
+    inner: iter::Enumerate<Iter<'a, T>>,
+}
+impl<'a, T> iter::Iterator for Pairs<'a, T> {
+    type Item = (&'a T, &'a T);
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (i, a) = self.cur?;
+            match self.inner.next() {
+                Some((j, _)) if j == i => continue,
+                Some((_, b)) => return Some((a, b)),
+                None => {
+                    self.cur = self.outer.next();
+                    self.inner = self.data.iter().enumerate();
+                },
+            }
+        }
+    }
+}
+/// drain_all返回的迭代器
+pub struct DrainAll<'a, T> {
+    data: IntoIter<T>,
+    marker: core::marker::PhantomData<&'a mut Link<T>>,
+}
+impl<T> iter::Iterator for DrainAll<'_, T> {
+    type Item = T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.data.next()
+    }
+}
+/// 与IntoIter相同，第一次调用next_back时把剩余元素移入VecDeque，之后两端都是O(1)
+/// # 例子
+/// ```
+/// use link::*;
+/// let mut l: Link<isize> = (0..5).collect();
+/// let v: Vec<isize> = l.drain_all().rev().collect();
+/// assert_eq!(v, [4, 3, 2, 1, 0]);
+/// assert!(l.is_empty());
+/// ```
+impl<T> iter::DoubleEndedIterator for DrainAll<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.data.next_back()
+    }
+}
+impl<T> DrainAll<'_, T> {
+    /// 查看尚未取出的元素组成的链表，调用过next_back之后剩余元素不再保存在链表中
+    pub fn as_link(&self) -> &Link<T> {
+        self.data.as_link()
+    }
+}
+/// drain返回的迭代器
+pub struct Drain<'a, T> {
+    // 指向范围内下一个元素的链接
+    slot: &'a mut Link<T>,
+    remaining: usize,
+}
+impl<T> iter::Iterator for Drain<'_, T> {
+    type Item = T;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let Node {value, next} = *self.slot.0.take()?;
+        *self.slot = next;
+        Some(value)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+impl<T> iter::ExactSizeIterator for Drain<'_, T> {}
+impl<T> Drop for Drain<'_, T> {
+    fn drop(&mut self) {
+        self.for_each(drop);
+    }
+}
 /// 元素迭代器
+#[must_use = "iterators are lazy and do nothing unless consumed"]
 pub struct IntoIter<T> {
-    data: Link<T>
+    data: Link<T>,
+    // 第一次调用next_back时从data中移出的剩余元素
+    back: alloc::collections::VecDeque<T>,
 }
 impl<T> iter::Iterator for IntoIter<T> {
     type Item = T;
     fn next(& mut self) -> Option<Self::Item> {
-        let node = self.data.0.take()?;
+        let node = match self.data.0.take() {
+            Some(node) => node,
+            None => return self.back.pop_front(),
+        };
         self.data = node.next;
         Some(node.value)
     }
 }
+/// 第一次调用next_back时把剩余元素移入VecDeque，之后两端都是O(1)
+/// # 例子
+/// ```
+/// use link::*;
+/// let l: Link<isize> = link![0, 1, 2, 3];
+/// let mut iter = l.into_iter();
+/// assert_eq!(iter.next_back(), Some(3));
+/// assert_eq!(iter.next(), Some(0));
+/// assert_eq!(format!("{:?}", iter.into_link()), "[1, 2]");
+/// let l: Link<isize> = link![0, 1, 2];
+/// assert_eq!(l.into_iter().rev().collect::<Vec<_>>(), [2, 1, 0]);
+/// ```
+impl<T> iter::DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        while let Some(v) = self.data.pop() {
+            self.back.push_back(v);
+        }
+        self.back.pop_back()
+    }
+}
+impl<T> IntoIter<T> {
+    /// 剩余未返回的元素个数，O(n)
+    pub fn remaining(&self) -> usize {
+        self.data.len() + self.back.len()
+    }
+    /// 查看剩余未返回的元素组成的链表，调用过next_back之后剩余元素不再保存在链表中，
+    /// 此时应使用into_link
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let l: Link<isize> = link![0, 1, 2];
+    /// let mut iter = l.into_iter();
+    /// iter.next();
+    /// assert_eq!(iter.remaining(), 2);
+    /// assert_eq!(format!("{:?}", iter.as_link()), "[1, 2]");
+    /// ```
+    pub fn as_link(&self) -> &Link<T> {
+        &self.data
+    }
+    /// 取回剩余未返回的元素组成的链表
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let l: Link<isize> = link![0, 1, 2];
+    /// let mut iter = l.into_iter();
+    /// iter.next();
+    /// let rest = iter.into_link();
+    /// assert_eq!(format!("{:?}", rest), "[1, 2]");
+    /// ```
+    pub fn into_link(self) -> Link<T> {
+        // 调用过next_back之后data总是为空
+        match self.back.is_empty() {
+            true => self.data,
+            false => self.back.into_iter().collect(),
+        }
+    }
+}
 /// 元素迭代适配器
 /// # 例子
 /// ```
@@ -541,7 +2574,7 @@ impl<T> iter::IntoIterator for Link<T> {
     type IntoIter = IntoIter<T>;
     
     fn into_iter(self) -> Self::IntoIter {
-        IntoIter {data: self}
+        IntoIter {data: self, back: alloc::collections::VecDeque::new()}
     }
 }
 /// 迭代转化器
@@ -556,12 +2589,30 @@ impl<T> iter::FromIterator<T> for Link<T> {
     where
         I: iter::IntoIterator<Item = T> {
         let mut link: Link<T> = Self::new();
-        let mut node = &mut link;
+        link.extend(iter);
+        link
+    }
+}
+/// 在尾部追加迭代器的元素，先找到尾部，再在尾部逐个接入新结点，总共只遍历一次
+/// # 例子
+/// ```
+/// use link::*;
+/// let mut a: Link<isize> = link![1, 2];
+/// a.extend(vec![3, 4]);
+/// assert_eq!(format!("{:?}", a), "[1, 2, 3, 4]");
+/// ```
+impl<T> iter::Extend<T> for Link<T> {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: iter::IntoIterator<Item = T> {
+        let mut node = &mut *self;
+        while node.0.is_some() {
+            node = &mut node.0.as_mut().unwrap().next;
+        }
         for i in iter {
             *node = Node::new(i, None).as_link();
             node = &mut node.0.as_mut().unwrap().next;
         }
-        link
     }
 }
 /// 迭代复制转化器
@@ -576,13 +2627,7 @@ impl<'a, T: Clone + 'a> iter::FromIterator<&'a T> for Link<T> {
     fn from_iter<I>(iter: I) -> Self 
     where
         I: iter::IntoIterator<Item = &'a T> {
-        let mut link: Link<T> = Self::new();
-        let mut node = &mut link;
-        for i in iter {
-            *node = Node::new(i.clone(), None).as_link();
-            node = &mut node.0.as_mut().unwrap().next;
-        }
-        link
+        iter.into_iter().cloned().collect()
     }
 }
 /// 创建链表的宏
@@ -596,21 +2641,167 @@ impl<'a, T: Clone + 'a> iter::FromIterator<&'a T> for Link<T> {
 /// assert_eq!(format!("{:?}", b), "[-1, -1, -1]");
 /// assert_eq!(format!("{:?}", c), "[]");
 /// ```
+/// 与vec!一样允许末尾的逗号和嵌套，`expr; n`中的两个表达式都只求值一次
+/// ```
+/// use link::*;
+/// let mut calls = 0;
+/// let mut next = || { calls += 1; calls };
+/// let a: Link<isize> = link![next(); 3];
+/// assert_eq!(format!("{:?}", a), "[1, 1, 1]");
+/// assert_eq!(calls, 1);
+/// let b: Link<Link<isize>> = link![
+///     link![1, 2,],
+///     link![],
+///     link![0; 2],
+/// ];
+/// assert_eq!(format!("{:?}", b), "[[1, 2], [], [0, 0]]");
+/// ```
+/// 无法识别的写法给出明确的编译错误
+/// ```compile_fail
+/// use link::*;
+/// let a: Link<isize> = link![1; 2; 3];
+/// ```
+/// ```compile_fail
+/// use link::*;
+/// let a: Link<isize> = link![,];
+/// ```
 #[macro_export]
 macro_rules! link {
-    ($($x:expr),+) => (vec![$($x),*].into_iter().collect());
+    () => ($crate::Link::new());
+    ($($x:expr),+ $(,)?) => (<$crate::Link<_> as ::core::convert::From<_>>::from([$($x),+]));
     ($x:expr; $n:expr) => ($crate::Link::from_elem($x, $n));
-    () => ($crate::Link::new())
+    ($($t:tt)*) => (::core::compile_error!("expected `link![]`, `link![a, b, ...]` or `link![elem; n]`"));
+}
+/// 写时复制的链表：借用已有链表时读取零拷贝，第一次通过to_mut修改时才克隆出自己的链表
+/// # 例子
+/// ```
+/// use link::*;
+/// fn normalize(l: &Link<isize>) -> CowLink<'_, isize> {
+///     let mut cow = CowLink::Borrowed(l);
+///     if l.iter().any(|v| *v < 0) {
+///         for v in cow.to_mut().iter_mut() {
+///             *v = v.abs();
+///         }
+///     }
+///     cow
+/// }
+/// let a: Link<isize> = link![1, 2];
+/// assert!(matches!(normalize(&a), CowLink::Borrowed(_)));
+/// let b: Link<isize> = link![1, -2];
+/// let c = normalize(&b);
+/// assert!(matches!(c, CowLink::Owned(_)));
+/// assert_eq!(format!("{:?}", c), "[1, 2]");
+/// ```
+pub type CowLink<'a, T> = alloc::borrow::Cow<'a, Link<T>>;
+/// 默认为空链表
+impl<T> Default for Link<T> {
+    fn default() -> Self {
+        Link::new()
+    }
+}
+/// 逐个释放结点，避免长链表递归析构导致栈溢出
+impl<T> Drop for Link<T> {
+    fn drop(&mut self) {
+        let mut cur = self.0.take();
+        while let Some(mut node) = cur {
+            cur = node.next.0.take();
+        }
+    }
+}
+use core::convert::TryFrom;
+/// 从定长数组创建链表
+/// # 例子
+/// ```
+/// use link::*;
+/// let l = Link::from([1, 2, 3]);
+/// assert_eq!(format!("{:?}", l), "[1, 2, 3]");
+/// ```
+impl<T, const N: usize> From<[T; N]> for Link<T> {
+    fn from(array: [T; N]) -> Self {
+        iter::IntoIterator::into_iter(array).collect()
+    }
+}
+/// 把链表转化为定长数组，长度不等于N时原样返回链表
+/// # 例子
+/// ```
+/// use core::convert::TryFrom;
+/// use link::*;
+/// let l: Link<isize> = link![1, 2, 3];
+/// assert_eq!(<[isize; 3]>::try_from(l.clone()), Ok([1, 2, 3]));
+/// assert!(<[isize; 4]>::try_from(l).is_err());
+/// ```
+impl<T, const N: usize> TryFrom<Link<T>> for [T; N] {
+    type Error = Link<T>;
+    fn try_from(link: Link<T>) -> Result<Self, Self::Error> {
+        if link.len() != N {
+            return Err(link);
+        }
+        let v: Vec<T> = link.into_iter().collect();
+        <[T; N]>::try_from(v).map_err(|v| v.into_iter().collect())
+    }
 }
-use std::fmt;
+use core::fmt;
 /// 格式化表示
 impl<T: fmt::Debug> fmt::Debug for Link<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_list().entries(self).finish()      
     }
 }
-use std::ops;
-/// 索引（查看）操作
+/// 从本节点起的链，格式为`2 -> 3 -> 4`，元素的格式选项（如精度）会传递给每个元素
+/// # 例子
+/// ```
+/// use link::*;
+/// let l: Link<f64> = link![1.0, 2.5, 3.0];
+/// let node = l.get(1).unwrap();
+/// assert_eq!(format!("{:?}", node), "2.5 -> 3.0");
+/// assert_eq!(format!("{}", node), "2.5 -> 3");
+/// assert_eq!(format!("{:.1}", l.get(0).unwrap()), "1.0 -> 2.5 -> 3.0");
+/// ```
+impl<T: fmt::Debug> fmt::Debug for Node<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_chain(f, T::fmt)
+    }
+}
+impl<T: fmt::Display> fmt::Display for Node<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_chain(f, T::fmt)
+    }
+}
+impl<T> Node<T> {
+    /// 用给定的元素格式化函数输出从本节点起的链
+    fn fmt_chain<F>(&self, f: &mut fmt::Formatter<'_>, fmt_value: F) -> fmt::Result
+    where
+        F: Fn(&T, &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_value(&self.value, f)?;
+        let mut node = self.next();
+        while let Some(n) = node {
+            f.write_str(" -> ")?;
+            fmt_value(&n.value, f)?;
+            node = n.next();
+        }
+        Ok(())
+    }
+}
+use core::ops;
+impl<T> ops::Index<usize> for Node<T> {
+    type Output = T;
+
+    fn index(&self, i: usize) -> &Self::Output {
+        match self.try_skip(i) {
+            Ok(n) => &n.value,
+            Err(len) => Link::<T>::out_of_range(i, len)
+        }
+    }
+}
+impl<T> ops::IndexMut<usize> for Node<T> {
+    fn index_mut(&mut self, i: usize) -> &mut Self::Output {
+        match self.try_skip_mut(i) {
+            Ok(n) => &mut n.value,
+            Err(len) => Link::<T>::out_of_range(i, len)
+        }
+    }
+}
+/// 索引（查看）操作，索引超出范围时恐慌，信息中包含链表长度
 /// # 例子
 /// ```
 /// use link::*;
@@ -618,14 +2809,17 @@ use std::ops;
 /// assert_eq!(a[0], 1);
 /// assert_eq!(a[1], 2);
 /// assert_eq!(a[2], 3);
+/// let err = std::panic::catch_unwind(|| a[7]).unwrap_err();
+/// assert_eq!(err.downcast_ref::<String>().unwrap(), "index 7 out of range for Link of length 3");
 /// ```
 impl<T> ops::Index<usize> for Link<T> {
     type Output = T;
 
     fn index(&self, i: usize) -> &Self::Output {
-        match self.get(i) {
-            Some(n) => &n.value,
-            None => Self::out_of_range(i)
+        match self.0.as_ref().map(|n| n.try_skip(i)) {
+            Some(Ok(n)) => &n.value,
+            Some(Err(len)) => Self::out_of_range(i, len),
+            None => Self::out_of_range(i, 0),
         }
     }
 }
@@ -639,9 +2833,10 @@ impl<T> ops::Index<usize> for Link<T> {
 /// ```
 impl<T> ops::IndexMut<usize> for Link<T> {
     fn index_mut(&mut self, i: usize) -> &mut Self::Output {
-        match self.get_mut(i) {
-            Some(n) => &mut n.value,
-            None => Self::out_of_range(i)
+        match self.0.as_mut().map(|n| n.try_skip_mut(i)) {
+            Some(Ok(n)) => &mut n.value,
+            Some(Err(len)) => Self::out_of_range(i, len),
+            None => Self::out_of_range(i, 0),
         }
     }
 }
@@ -710,7 +2905,7 @@ impl<T> ops::AddAssign for Link<T> {
 /// let a: Link<isize> = link![1, 2, 3];
 /// assert_eq!(a, link![1, 2, 3]);
 /// ```
-use std::cmp;
+use core::cmp;
 impl<T> cmp::PartialEq for Link<T> 
 where 
     T: cmp::PartialEq {
@@ -733,4 +2928,11 @@ mod tests {
     assert_eq!(a, link![1, 2]);
     assert_eq!(b, link![3]);
     }
+    #[test]
+    fn drop_long_link() {
+        use crate::*;
+        let l: Link<usize> = (0..1_000_000).collect();
+        assert_eq!(l.len(), 1_000_000);
+        drop(l);
+    }
 }
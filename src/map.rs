@@ -0,0 +1,202 @@
+//! 以链表为桶的哈希表
+use crate::{Iter, Link, Node};
+use core::borrow::Borrow;
+use core::fmt;
+use core::hash::{BuildHasher, Hash};
+use core::iter::FromIterator;
+use core::mem;
+use std::collections::hash_map::RandomState;
+
+/// 第一次插入时分配的桶数
+const MIN_BUCKETS: usize = 8;
+/// 链地址法的哈希表，每个桶是一个Link<(K, V)>，
+/// 元素个数超过桶数的3/4时桶数翻倍，扩容时把原有结点重新链接到新桶中，不重新分配结点。
+/// 与std的HashMap相同，默认使用随机密钥的RandomState，抵御构造冲突键的HashDoS攻击
+/// # 例子
+/// ```
+/// use link::map::ChainMap;
+/// let mut m = ChainMap::new();
+/// for (i, w) in ["a", "b", "c", "a"].iter().enumerate() {
+///     m.insert(*w, i);
+/// }
+/// assert_eq!(m.len(), 3);
+/// assert_eq!(m.get("a"), Some(&3));
+/// *m.get_mut("b").unwrap() += 10;
+/// assert_eq!(m.remove("b"), Some(11));
+/// assert!(!m.contains_key("b"));
+/// let mut pairs: Vec<_> = m.iter().collect();
+/// pairs.sort();
+/// assert_eq!(pairs, [(&"a", &3), (&"c", &2)]);
+/// ```
+pub struct ChainMap<K, V, S = RandomState> {
+    buckets: Vec<Link<(K, V)>>,
+    len: usize,
+    hasher: S,
+}
+impl<K: Hash + Eq, V> ChainMap<K, V> {
+    /// 创建空表，不分配内存
+    pub fn new() -> Self {
+        Self::with_hasher(Default::default())
+    }
+}
+impl<K: Hash + Eq, V, S: BuildHasher> ChainMap<K, V, S> {
+    /// 用指定的哈希器创建空表
+    pub fn with_hasher(hasher: S) -> Self {
+        ChainMap {buckets: Vec::new(), len: 0, hasher}
+    }
+    /// 键所在的桶
+    fn bucket<Q>(&self, k: &Q) -> usize
+    where
+        Q: Hash + ?Sized {
+        self.hasher.hash_one(k) as usize & (self.buckets.len() - 1)
+    }
+    /// 桶数翻倍，把原有结点逐个摘下挂到新桶的头部
+    fn grow(&mut self) {
+        let n = (self.buckets.len() * 2).max(MIN_BUCKETS);
+        let old = mem::replace(&mut self.buckets, (0..n).map(|_| Link::new()).collect());
+        for mut b in old {
+            while let Some(mut node) = b.0.take() {
+                b = node.next.take();
+                let i = self.bucket(&node.value.0);
+                node.next = self.buckets[i].take();
+                self.buckets[i] = Link(Some(node));
+            }
+        }
+    }
+    /// 插入键值对，键已存在时替换并返回原来的值
+    pub fn insert(&mut self, k: K, v: V) -> Option<V> {
+        if let Some(old) = self.get_mut(&k) {
+            return Some(mem::replace(old, v));
+        }
+        if (self.len + 1) * 4 > self.buckets.len() * 3 {
+            self.grow();
+        }
+        let i = self.bucket(&k);
+        self.buckets[i].push((k, v));
+        self.len += 1;
+        None
+    }
+    /// 获取键对应的值
+    pub fn get<Q>(&self, k: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized {
+        if self.len == 0 {
+            return None;
+        }
+        self.buckets[self.bucket(k)].iter().find(|(key, _)| key.borrow() == k).map(|(_, v)| v)
+    }
+    /// 获取键对应的值的可变引用
+    pub fn get_mut<Q>(&mut self, k: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized {
+        if self.len == 0 {
+            return None;
+        }
+        let i = self.bucket(k);
+        self.buckets[i].iter_mut().find(|(key, _)| key.borrow() == k).map(|(_, v)| v)
+    }
+    /// 判断键是否存在
+    pub fn contains_key<Q>(&self, k: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized {
+        self.get(k).is_some()
+    }
+    /// 删除键并返回它的值，键不存在时返回None
+    pub fn remove<Q>(&mut self, k: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized {
+        if self.len == 0 {
+            return None;
+        }
+        let i = self.bucket(k);
+        let mut node = &mut self.buckets[i];
+        while let Some(n) = node.0.as_ref() {
+            if n.value.0.borrow() == k {
+                let Node {value, next} = *node.0.take().unwrap();
+                *node = next;
+                self.len -= 1;
+                return Some(value.1);
+            }
+            node = &mut node.0.as_mut().unwrap().next;
+        }
+        None
+    }
+}
+impl<K, V, S> ChainMap<K, V, S> {
+    /// 获取键值对个数，O(1)
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    /// 判断是否为空
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    /// 当前的桶数
+    /// # 例子
+    /// ```
+    /// use link::map::ChainMap;
+    /// let mut m: ChainMap<u32, u32> = (0..1000).map(|i| (i, i * i)).collect();
+    /// assert!(m.bucket_count() >= 1000 * 4 / 3);
+    /// assert!((0..1000).all(|i| m.get(&i) == Some(&(i * i))));
+    /// m.clear();
+    /// assert!(m.is_empty() && m.bucket_count() > 0);
+    /// ```
+    pub fn bucket_count(&self) -> usize {
+        self.buckets.len()
+    }
+    /// 删除全部键值对，保留桶
+    pub fn clear(&mut self) {
+        for b in &mut self.buckets {
+            *b = Link::new();
+        }
+        self.len = 0;
+    }
+    /// 以任意顺序遍历全部键值对
+    pub fn iter(&self) -> MapIter<'_, K, V> {
+        MapIter {buckets: self.buckets.iter(), cur: None}
+    }
+}
+impl<K: Hash + Eq, V> Default for ChainMap<K, V> {
+    fn default() -> Self {
+        ChainMap::new()
+    }
+}
+impl<K: Hash + Eq, V, S: BuildHasher> Extend<(K, V)> for ChainMap<K, V, S> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (k, v) in iter {
+            self.insert(k, v);
+        }
+    }
+}
+impl<K: Hash + Eq, V> FromIterator<(K, V)> for ChainMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut m = ChainMap::new();
+        m.extend(iter);
+        m
+    }
+}
+impl<K: fmt::Debug, V: fmt::Debug, S> fmt::Debug for ChainMap<K, V, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+/// ChainMap的迭代器
+pub struct MapIter<'a, K, V> {
+    buckets: core::slice::Iter<'a, Link<(K, V)>>,
+    cur: Option<Iter<'a, (K, V)>>,
+}
+impl<'a, K, V> Iterator for MapIter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((k, v)) = self.cur.as_mut().and_then(Iterator::next) {
+                return Some((k, v));
+            }
+            self.cur = Some(self.buckets.next()?.iter());
+        }
+    }
+}
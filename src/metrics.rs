@@ -0,0 +1,54 @@
+//! 全局的操作计数器，用于客观比较不同实现的开销
+//!
+//! 计数器在所有链表之间共享，使用Relaxed原子操作，只在启用metrics特性时编译。
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+pub(crate) static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+pub(crate) static MOVES: AtomicUsize = AtomicUsize::new(0);
+pub(crate) static STEPS: AtomicUsize = AtomicUsize::new(0);
+
+/// 计数器的快照
+/// # 例子
+/// ```
+/// use link::*;
+/// let before = link::metrics::stats();
+/// let mut l: Link<isize> = link![1, 2, 3];
+/// l.pop_back();
+/// let diff = link::metrics::stats().since(&before);
+/// assert!(diff.allocations >= 3);
+/// assert!(diff.moves >= 4);
+/// assert!(diff.steps >= 1);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// 分配的结点个数
+    pub allocations: usize,
+    /// 元素移入或移出结点的次数
+    pub moves: usize,
+    /// 沿next链接前进的步数
+    pub steps: usize,
+}
+impl Stats {
+    /// 计算从earlier到当前快照的增量，适合在其他测试并发运行时测量一段代码
+    pub fn since(&self, earlier: &Stats) -> Stats {
+        Stats {
+            allocations: self.allocations.wrapping_sub(earlier.allocations),
+            moves: self.moves.wrapping_sub(earlier.moves),
+            steps: self.steps.wrapping_sub(earlier.steps),
+        }
+    }
+}
+/// 获取当前计数器的快照
+pub fn stats() -> Stats {
+    Stats {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed),
+        moves: MOVES.load(Ordering::Relaxed),
+        steps: STEPS.load(Ordering::Relaxed),
+    }
+}
+/// 把所有计数器清零
+pub fn reset() {
+    ALLOCATIONS.store(0, Ordering::Relaxed);
+    MOVES.store(0, Ordering::Relaxed);
+    STEPS.store(0, Ordering::Relaxed);
+}
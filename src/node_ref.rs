@@ -0,0 +1,106 @@
+//! 地址稳定的结点引用
+use crate::{Link, Node};
+use core::ops;
+
+impl<T> Link<T> {
+    /// 第i个结点的引用，超出范围时返回None
+    pub fn node_ref(&self, i: usize) -> Option<NodeRef<'_, T>> {
+        self.get(i).map(|node| NodeRef {node})
+    }
+    /// 按顺序生成每个结点的引用
+    pub fn node_refs(&self) -> NodeRefs<'_, T> {
+        NodeRefs {next: self.0.as_deref()}
+    }
+    /// 查找元素地址为addr的结点的索引，addr通常来自之前的NodeRef::addr，
+    /// 可以用来在不持有借用的情况下记住一个结点，之后再找回它
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let mut l: Link<isize> = link![10, 20, 30];
+    /// let waiter = l.node_ref(1).unwrap().addr();
+    /// l.push(0);
+    /// l.reverse();
+    /// assert_eq!(l.position_of_addr(waiter), Some(1));
+    /// let v = l.delete(1).unwrap();
+    /// assert_eq!(v, 20);
+    /// assert_eq!(l.position_of_addr(waiter), None);
+    /// ```
+    pub fn position_of_addr(&self, addr: *const T) -> Option<usize> {
+        self.node_refs().position(|n| core::ptr::eq(n.addr(), addr))
+    }
+}
+/// 链表中一个结点的不可变引用，借用期间结点不会移动也不会被释放，
+/// addr在元素离开链表之前保持不变，链接被修改后也是如此
+/// # 例子
+/// ```
+/// use link::*;
+/// let mut l: Link<isize> = link![3, 1, 2];
+/// let before: Vec<*const isize> = l.node_refs().map(|n| n.addr()).collect();
+/// l.reverse();
+/// l.sort();
+/// let mut c = l.cursor_mut();
+/// c.insert_before(0);
+/// c.splice_after(link![9]);
+/// drop(c);
+/// // 只修改了链接，原有的三个元素仍在原来的地址上
+/// for (i, addr) in before.iter().enumerate() {
+///     let pos = l.position_of_addr(*addr).unwrap();
+///     assert_eq!(l[pos], [3, 1, 2][i]);
+/// }
+/// let first = l.node_ref(0).unwrap();
+/// assert_eq!(*first, 0);
+/// assert!(first.next().unwrap().ptr_eq(&l.node_ref(1).unwrap()));
+/// ```
+/// 持有NodeRef期间不能修改链表，因此结点不会在使用中被释放
+/// ```compile_fail
+/// use link::*;
+/// let mut l: Link<isize> = link![1, 2];
+/// let n = l.node_ref(0).unwrap();
+/// l.pop();
+/// assert_eq!(*n, 1);
+/// ```
+pub struct NodeRef<'a, T> {
+    node: &'a Node<T>,
+}
+impl<'a, T> NodeRef<'a, T> {
+    /// 元素的引用，生命周期与链表的借用相同
+    pub fn get(&self) -> &'a T {
+        &self.node.value
+    }
+    /// 元素的地址
+    pub fn addr(&self) -> *const T {
+        &self.node.value
+    }
+    /// 下一个结点的引用
+    pub fn next(&self) -> Option<NodeRef<'a, T>> {
+        self.node.next().map(|node| NodeRef {node})
+    }
+    /// 判断两个引用是否指向同一个结点
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        core::ptr::eq(self.node, other.node)
+    }
+}
+impl<T> Clone for NodeRef<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<T> Copy for NodeRef<'_, T> {}
+impl<T> ops::Deref for NodeRef<'_, T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        &self.node.value
+    }
+}
+/// node_refs返回的迭代器
+pub struct NodeRefs<'a, T> {
+    next: Option<&'a Node<T>>,
+}
+impl<'a, T> Iterator for NodeRefs<'a, T> {
+    type Item = NodeRef<'a, T>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.next?;
+        self.next = node.next();
+        Some(NodeRef {node})
+    }
+}
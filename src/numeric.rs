@@ -0,0 +1,141 @@
+//! 数值链表的逐元素运算
+use crate::Link;
+use core::fmt;
+use core::ops;
+
+impl<T> Link<T> {
+    /// 每个元素加上同一个标量
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let mut l: Link<f64> = link![1.0, 2.0];
+    /// l.add_assign_scalar(0.5);
+    /// l.mul_assign_scalar(2.0);
+    /// assert_eq!(format!("{:?}", l), "[3.0, 5.0]");
+    /// ```
+    pub fn add_assign_scalar<S>(&mut self, s: S)
+    where
+        S: Clone,
+        T: ops::AddAssign<S> {
+        for v in self.iter_mut() {
+            *v += s.clone();
+        }
+    }
+    /// 每个元素乘以同一个标量
+    pub fn mul_assign_scalar<S>(&mut self, s: S)
+    where
+        S: Clone,
+        T: ops::MulAssign<S> {
+        for v in self.iter_mut() {
+            *v *= s.clone();
+        }
+    }
+    /// 用每个元素和同一个标量计算出新的链表
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let l: Link<i32> = link![1, 2, 3];
+    /// let r: Link<i32> = l.map_scalar(&10, |v, s| v * s + 1);
+    /// assert_eq!(format!("{:?}", r), "[11, 21, 31]");
+    /// ```
+    pub fn map_scalar<S, U, F>(&self, s: &S, mut f: F) -> Link<U>
+    where
+        F: FnMut(&T, &S) -> U {
+        self.iter().map(|v| f(v, s)).collect()
+    }
+}
+/// 支持运算符的数值链表，与标量运算时作用于每个元素，
+/// 两个Numeric之间的加减按位置逐元素进行，长度不同时恐慌
+/// # 例子
+/// ```
+/// use link::*;
+/// let a = Numeric::from(link![1, 2, 3]);
+/// let b = Numeric::from(link![10, 20, 30]);
+/// let c = (a * 2 + b) - 1;
+/// assert_eq!(format!("{:?}", c), "[11, 23, 35]");
+/// assert_eq!(format!("{:?}", -c), "[-11, -23, -35]");
+/// ```
+#[derive(Clone, Default)]
+pub struct Numeric<T>(pub Link<T>);
+impl<T> Numeric<T> {
+    /// 取出内部链表
+    pub fn into_inner(self) -> Link<T> {
+        self.0
+    }
+    /// 逐元素运算两个链表
+    fn zip_with<F>(self, rhs: Self, mut f: F) -> Self
+    where
+        F: FnMut(T, T) -> T {
+        let (la, lb) = (self.0.len(), rhs.0.len());
+        if la != lb {
+            panic!("length mismatch: {} vs {}", la, lb);
+        }
+        Numeric(self.0.into_iter().zip(rhs.0).map(|(a, b)| f(a, b)).collect())
+    }
+}
+impl<T> From<Link<T>> for Numeric<T> {
+    fn from(link: Link<T>) -> Self {
+        Numeric(link)
+    }
+}
+impl<T> ops::Deref for Numeric<T> {
+    type Target = Link<T>;
+    fn deref(&self) -> &Link<T> {
+        &self.0
+    }
+}
+impl<T> ops::DerefMut for Numeric<T> {
+    fn deref_mut(&mut self) -> &mut Link<T> {
+        &mut self.0
+    }
+}
+impl<T: fmt::Debug> fmt::Debug for Numeric<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+macro_rules! scalar_op {
+    ($($tr:ident $m:ident $atr:ident $am:ident;)*) => {$(
+        impl<T> ops::$tr<T> for Numeric<T>
+        where
+            T: ops::$tr<Output = T> + Clone {
+            type Output = Numeric<T>;
+            fn $m(self, s: T) -> Numeric<T> {
+                Numeric(self.0.into_iter().map(|v| ops::$tr::$m(v, s.clone())).collect())
+            }
+        }
+        impl<T> ops::$atr<T> for Numeric<T>
+        where
+            T: ops::$atr + Clone {
+            fn $am(&mut self, s: T) {
+                for v in self.0.iter_mut() {
+                    ops::$atr::$am(v, s.clone());
+                }
+            }
+        }
+    )*};
+}
+scalar_op! {
+    Add add AddAssign add_assign;
+    Sub sub SubAssign sub_assign;
+    Mul mul MulAssign mul_assign;
+    Div div DivAssign div_assign;
+}
+impl<T: ops::Add<Output = T>> ops::Add for Numeric<T> {
+    type Output = Numeric<T>;
+    fn add(self, rhs: Self) -> Numeric<T> {
+        self.zip_with(rhs, |a, b| a + b)
+    }
+}
+impl<T: ops::Sub<Output = T>> ops::Sub for Numeric<T> {
+    type Output = Numeric<T>;
+    fn sub(self, rhs: Self) -> Numeric<T> {
+        self.zip_with(rhs, |a, b| a - b)
+    }
+}
+impl<T: ops::Neg<Output = T>> ops::Neg for Numeric<T> {
+    type Output = Numeric<T>;
+    fn neg(self) -> Numeric<T> {
+        Numeric(self.0.into_iter().map(|v| -v).collect())
+    }
+}
@@ -0,0 +1,71 @@
+//! 基于rayon的并行操作
+use crate::Link;
+use rayon::prelude::*;
+use core::cmp::Ordering;
+
+impl<T: Send> Link<T> {
+    /// 按线程数把链表切成长度相近的若干段
+    fn into_chunks(mut self) -> Vec<Self> {
+        let size = self.len().div_ceil(rayon::current_num_threads()).max(1);
+        let mut chunks = Vec::new();
+        while !self.is_empty() {
+            let next = self.split_off(size - 1);
+            chunks.push(self);
+            self = next;
+        }
+        chunks
+    }
+    /// 并行排序，把链表分块后并行排序各块，再通过修改链接归并，排序是稳定的
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let mut l: Link<isize> = link![3, 1, 4, 1, 5, 9, 2, 6];
+    /// l.par_sort();
+    /// assert_eq!(format!("{:?}", l), "[1, 1, 2, 3, 4, 5, 6, 9]");
+    /// ```
+    pub fn par_sort(&mut self)
+    where
+        T: Ord {
+        self.par_sort_by(T::cmp);
+    }
+    /// 用比较函数并行排序，排序是稳定的
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let mut l: Link<isize> = link![3, 1, 4, 1, 5];
+    /// l.par_sort_by(|a, b| b.cmp(a));
+    /// assert_eq!(format!("{:?}", l), "[5, 4, 3, 1, 1]");
+    /// ```
+    pub fn par_sort_by<F>(&mut self, cmp: F)
+    where
+        F: Fn(&T, &T) -> Ordering + Sync {
+        trace_op!("par_sort", self);
+        let mut chunks = self.take().into_chunks();
+        chunks.par_iter_mut().for_each(|c| c.sort_by(&cmp));
+        *self = chunks.into_par_iter().reduce(Link::new, |a, b| Link::merge_by(a, b, &mut &cmp));
+    }
+    /// 并行映射，把链表分块后在多个线程中映射各块，再按原顺序连接结果
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let l: Link<u64> = (0..1000).collect();
+    /// let squares = l.par_map(|v| v * v);
+    /// assert_eq!(squares.len(), 1000);
+    /// assert_eq!(squares[999], 999 * 999);
+    /// assert!(squares.iter().zip(0u64..).all(|(s, i)| *s == i * i));
+    /// ```
+    pub fn par_map<U, F>(self, f: F) -> Link<U>
+    where
+        U: Send,
+        F: Fn(T) -> U + Sync {
+        let chunks: Vec<Link<U>> = self.into_chunks()
+            .into_par_iter()
+            .map(|c| c.into_iter().map(&f).collect())
+            .collect();
+        // 从后往前连接，每段只遍历一次
+        chunks.into_iter().rev().fold(Link::new(), |rest, mut c| {
+            c.concat(rest);
+            c
+        })
+    }
+}
@@ -0,0 +1,169 @@
+//! 不可变的持久化链表
+use alloc::rc::Rc;
+use core::fmt;
+use core::iter::FromIterator;
+
+struct PNode<T> {
+    value: T,
+    next: Option<Rc<PNode<T>>>,
+}
+/// 持久化链表，所有修改操作都返回新版本，新旧版本共享未改变的尾部，
+/// 克隆只增加引用计数，长度被缓存
+/// # 例子
+/// ```
+/// use link::persistent::PersistentLink;
+/// let a: PersistentLink<isize> = PersistentLink::new().push_front(3).push_front(2);
+/// let b = a.push_front(1);
+/// let c = b.update(2, 4).unwrap();
+/// assert_eq!(format!("{:?}", a), "[2, 3]");
+/// assert_eq!(format!("{:?}", b), "[1, 2, 3]");
+/// assert_eq!(format!("{:?}", c), "[1, 2, 4]");
+/// assert_eq!(b.pop_front(), Some((&1, a.clone())));
+/// assert_eq!(c.len(), 3);
+/// ```
+pub struct PersistentLink<T> {
+    head: Option<Rc<PNode<T>>>,
+    len: usize,
+}
+impl<T> PersistentLink<T> {
+    /// 创建空链表
+    pub fn new() -> Self {
+        PersistentLink {head: None, len: 0}
+    }
+    /// 获取链表长度，O(1)
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    /// 判断链表是否为空
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+    /// 获取第一个元素
+    pub fn front(&self) -> Option<&T> {
+        self.head.as_ref().map(|n| &n.value)
+    }
+    /// 获取第i个元素
+    pub fn get(&self, i: usize) -> Option<&T> {
+        self.iter().nth(i)
+    }
+    /// 返回在头部压入元素后的新版本，O(1)，与原链表共享全部结点
+    pub fn push_front(&self, val: T) -> Self {
+        let node = PNode {value: val, next: self.head.clone()};
+        PersistentLink {head: Some(Rc::new(node)), len: self.len + 1}
+    }
+    /// 返回第一个元素和去掉它之后的新版本，O(1)，空链表返回None
+    pub fn pop_front(&self) -> Option<(&T, Self)> {
+        let node = self.head.as_ref()?;
+        Some((&node.value, PersistentLink {head: node.next.clone(), len: self.len - 1}))
+    }
+    /// 返回第i个元素替换为val后的新版本，O(i)，只复制前i个结点，
+    /// 之后的结点与原链表共享，索引超出范围时返回None
+    /// # 例子
+    /// ```
+    /// use link::persistent::PersistentLink;
+    /// let a: PersistentLink<isize> = vec![1, 2, 3].into_iter().collect();
+    /// assert_eq!(format!("{:?}", a.update(0, 0).unwrap()), "[0, 2, 3]");
+    /// assert!(a.update(3, 0).is_none());
+    /// ```
+    pub fn update(&self, i: usize, val: T) -> Option<Self>
+    where
+        T: Clone {
+        if i >= self.len {
+            return None;
+        }
+        let mut prefix = alloc::vec::Vec::with_capacity(i);
+        let mut node = self.head.as_ref()?;
+        for _ in 0..i {
+            prefix.push(node.value.clone());
+            node = node.next.as_ref()?;
+        }
+        let mut head = Some(Rc::new(PNode {value: val, next: node.next.clone()}));
+        for value in prefix.into_iter().rev() {
+            head = Some(Rc::new(PNode {value, next: head}));
+        }
+        Some(PersistentLink {head, len: self.len})
+    }
+    /// 生成不可变迭代器
+    pub fn iter(&self) -> PersistentIter<'_, T> {
+        PersistentIter {node: self.head.as_deref(), len: self.len}
+    }
+}
+impl<T> Clone for PersistentLink<T> {
+    fn clone(&self) -> Self {
+        PersistentLink {head: self.head.clone(), len: self.len}
+    }
+}
+impl<T> Default for PersistentLink<T> {
+    fn default() -> Self {
+        PersistentLink::new()
+    }
+}
+/// 逐个释放不再被共享的结点，避免长链表递归析构导致栈溢出
+impl<T> Drop for PersistentLink<T> {
+    fn drop(&mut self) {
+        let mut head = self.head.take();
+        while let Some(node) = head {
+            head = match Rc::try_unwrap(node) {
+                Ok(mut node) => node.next.take(),
+                Err(_) => break,
+            };
+        }
+    }
+}
+/// 比较元素是否相等，遇到共享的结点时直接判定剩余部分相等
+impl<T: PartialEq> PartialEq for PersistentLink<T> {
+    fn eq(&self, other: &Self) -> bool {
+        if self.len != other.len {
+            return false;
+        }
+        let (mut a, mut b) = (self.head.as_ref(), other.head.as_ref());
+        while let (Some(x), Some(y)) = (a, b) {
+            if Rc::ptr_eq(x, y) {
+                return true;
+            }
+            if x.value != y.value {
+                return false;
+            }
+            a = x.next.as_ref();
+            b = y.next.as_ref();
+        }
+        true
+    }
+}
+impl<T: Eq> Eq for PersistentLink<T> {}
+impl<T: fmt::Debug> fmt::Debug for PersistentLink<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+impl<T> FromIterator<T> for PersistentLink<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let values: alloc::vec::Vec<T> = iter.into_iter().collect();
+        values.into_iter().rev().fold(PersistentLink::new(), |l, v| l.push_front(v))
+    }
+}
+impl<'a, T> IntoIterator for &'a PersistentLink<T> {
+    type Item = &'a T;
+    type IntoIter = PersistentIter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+/// PersistentLink的不可变迭代器
+pub struct PersistentIter<'a, T> {
+    node: Option<&'a PNode<T>>,
+    len: usize,
+}
+impl<'a, T> Iterator for PersistentIter<'a, T> {
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.node?;
+        self.node = node.next.as_deref();
+        self.len -= 1;
+        Some(&node.value)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+impl<T> ExactSizeIterator for PersistentIter<'_, T> {}
@@ -0,0 +1,333 @@
+//! 不依赖堆分配的定长对象池和基于它的链表
+use crate::CapacityExceeded;
+use core::fmt;
+use core::mem;
+
+/// FreeList中元素的句柄，元素被释放之前句柄一直有效且指向同一个槽位，
+/// 释放后槽位的代数增加，旧句柄不会误指向之后放入同一槽位的元素
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Handle {
+    index: usize,
+    generation: u32,
+}
+impl Handle {
+    /// 槽位的索引
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+/// 槽位的状态
+enum State<T> {
+    /// 空闲，记录下一个空闲槽位
+    Vacant(Option<usize>),
+    /// 已占用
+    Occupied(T),
+}
+/// 结点池中的槽位
+struct Slot<T> {
+    generation: u32,
+    state: State<T>,
+}
+/// 定长的对象池，已释放的槽位串成链表，占用和释放都是O(1)，不进行任何堆分配。
+/// new把N个槽位都初始化为空闲，为O(N)；从未使用过的槽位按顺序分配，不需要预先串成空闲链表
+/// # 例子
+/// ```
+/// use link::pool::FreeList;
+/// let mut pool: FreeList<&str, 2> = FreeList::new();
+/// let a = pool.acquire("a").unwrap();
+/// let b = pool.acquire("b").unwrap();
+/// assert!(pool.acquire("c").is_err());
+/// assert_eq!(pool.release(a), Some("a"));
+/// assert_eq!(pool.get(a), None);
+/// let c = pool.acquire("c").unwrap();
+/// assert_eq!(c.index(), a.index());
+/// assert_eq!(pool.get(c), Some(&"c"));
+/// assert_eq!(pool.get(b), Some(&"b"));
+/// ```
+pub struct FreeList<T, const N: usize> {
+    slots: [Slot<T>; N],
+    // 已释放的空闲槽位组成的链表
+    free: Option<usize>,
+    // 从未使用过的槽位的起点
+    unused: usize,
+    len: usize,
+}
+impl<T, const N: usize> FreeList<T, N> {
+    const VACANT: Slot<T> = Slot {generation: 0, state: State::Vacant(None)};
+    /// 创建空的对象池
+    pub const fn new() -> Self {
+        FreeList {slots: [Self::VACANT; N], free: None, unused: 0, len: 0}
+    }
+    /// 获取容量
+    pub fn capacity(&self) -> usize {
+        N
+    }
+    /// 已占用的槽位个数
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    /// 判断是否没有占用的槽位
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    /// 判断是否已满
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+    /// 放入元素并返回句柄，池满时返回携带原值的CapacityExceeded
+    pub fn acquire(&mut self, value: T) -> Result<Handle, CapacityExceeded<T>> {
+        let i = match self.free {
+            Some(i) => {
+                if let State::Vacant(next) = self.slots[i].state {
+                    self.free = next;
+                }
+                i
+            },
+            None if self.unused < N => {
+                self.unused += 1;
+                self.unused - 1
+            },
+            None => return Err(CapacityExceeded(value)),
+        };
+        let slot = &mut self.slots[i];
+        slot.state = State::Occupied(value);
+        self.len += 1;
+        Ok(Handle {index: i, generation: slot.generation})
+    }
+    /// 释放句柄指向的元素并返回它，句柄已失效时返回None
+    pub fn release(&mut self, h: Handle) -> Option<T> {
+        self.get(h)?;
+        let slot = &mut self.slots[h.index];
+        slot.generation = slot.generation.wrapping_add(1);
+        match mem::replace(&mut slot.state, State::Vacant(self.free)) {
+            State::Occupied(value) => {
+                self.free = Some(h.index);
+                self.len -= 1;
+                Some(value)
+            },
+            State::Vacant(_) => unreachable!(),
+        }
+    }
+    /// 判断句柄是否仍然有效
+    pub fn contains(&self, h: Handle) -> bool {
+        self.get(h).is_some()
+    }
+    /// 获取句柄指向的元素，句柄已失效时返回None
+    pub fn get(&self, h: Handle) -> Option<&T> {
+        match self.slots.get(h.index)? {
+            Slot {generation, state: State::Occupied(value)} if *generation == h.generation => Some(value),
+            _ => None,
+        }
+    }
+    /// 获取句柄指向的元素的可变引用，句柄已失效时返回None
+    pub fn get_mut(&mut self, h: Handle) -> Option<&mut T> {
+        match self.slots.get_mut(h.index)? {
+            Slot {generation, state: State::Occupied(value)} if *generation == h.generation => Some(value),
+            _ => None,
+        }
+    }
+}
+impl<T, const N: usize> Default for FreeList<T, N> {
+    fn default() -> Self {
+        FreeList::new()
+    }
+}
+/// StaticLink::insert失败的原因，两种情况都携带未能插入的元素
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum InsertError<T> {
+    /// 结点池已满
+    Full(T),
+    /// 插入位置超出链表长度
+    OutOfRange(T),
+}
+impl<T> InsertError<T> {
+    /// 取回未能插入的元素
+    pub fn into_inner(self) -> T {
+        match self {
+            InsertError::Full(v) | InsertError::OutOfRange(v) => v,
+        }
+    }
+}
+impl<T> fmt::Debug for InsertError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InsertError::Full(_) => f.write_str("Full(..)"),
+            InsertError::OutOfRange(_) => f.write_str("OutOfRange(..)"),
+        }
+    }
+}
+impl<T> fmt::Display for InsertError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InsertError::Full(_) => f.write_str("node pool is full"),
+            InsertError::OutOfRange(_) => f.write_str("insertion index out of range"),
+        }
+    }
+}
+impl<T> core::error::Error for InsertError<T> {}
+/// 池中的结点
+struct PoolNode<T> {
+    value: T,
+    next: Option<Handle>,
+}
+/// 结点取自内部定长结点池的单向链表，最多容纳N个元素，不进行任何堆分配，
+/// new是const fn，可以直接放在static中使用
+/// # 例子
+/// ```
+/// use std::sync::Mutex;
+/// use link::pool::StaticLink;
+/// static QUEUE: Mutex<StaticLink<u32, 4>> = Mutex::new(StaticLink::new());
+/// let mut q = QUEUE.lock().unwrap();
+/// q.push_back(1).unwrap();
+/// q.push_back(2).unwrap();
+/// q.push(0).unwrap();
+/// assert_eq!(format!("{:?}", *q), "[0, 1, 2]");
+/// assert_eq!(q.pop(), Some(0));
+/// ```
+pub struct StaticLink<T, const N: usize> {
+    pool: FreeList<PoolNode<T>, N>,
+    head: Option<Handle>,
+}
+impl<T, const N: usize> StaticLink<T, N> {
+    /// 创建空链表
+    pub const fn new() -> Self {
+        StaticLink {pool: FreeList::new(), head: None}
+    }
+    /// 获取容量
+    pub fn capacity(&self) -> usize {
+        N
+    }
+    /// 获取链表长度，O(1)
+    pub fn len(&self) -> usize {
+        self.pool.len()
+    }
+    /// 判断链表是否为空
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+    /// 判断结点池是否已满
+    pub fn is_full(&self) -> bool {
+        self.len() == N
+    }
+    /// 链表中的结点，链表持有的句柄总是有效的
+    fn node(&self, h: Handle) -> &PoolNode<T> {
+        self.pool.get(h).expect("dangling node handle")
+    }
+    fn node_mut(&mut self, h: Handle) -> &mut PoolNode<T> {
+        self.pool.get_mut(h).expect("dangling node handle")
+    }
+    /// 第i个结点的句柄
+    fn slot(&self, i: usize) -> Option<Handle> {
+        let mut cur = self.head?;
+        for _ in 0..i {
+            cur = self.node(cur).next?;
+        }
+        Some(cur)
+    }
+    /// 在指定位置插入元素，结点池已满时返回InsertError::Full，
+    /// 位置超出链表长度时返回InsertError::OutOfRange，两者都携带原值
+    /// # 例子
+    /// ```
+    /// use link::pool::{InsertError, StaticLink};
+    /// let mut l: StaticLink<isize, 3> = StaticLink::new();
+    /// l.insert(0, 1).unwrap();
+    /// assert_eq!(l.insert(2, 3), Err(InsertError::OutOfRange(3)));
+    /// l.insert(1, 3).unwrap();
+    /// l.insert(1, 2).unwrap();
+    /// assert_eq!(format!("{:?}", l), "[1, 2, 3]");
+    /// assert_eq!(l.insert(0, 0), Err(InsertError::Full(0)));
+    /// ```
+    pub fn insert(&mut self, i: usize, val: T) -> Result<(), InsertError<T>> {
+        let prev = match i {
+            0 => None,
+            _ => match self.slot(i - 1) {
+                Some(p) => Some(p),
+                None => return Err(InsertError::OutOfRange(val)),
+            },
+        };
+        let next = match prev {
+            Some(p) => self.node(p).next,
+            None => self.head,
+        };
+        let n = self.pool.acquire(PoolNode {value: val, next}).map_err(|e| InsertError::Full(e.0.value))?;
+        match prev {
+            Some(p) => self.node_mut(p).next = Some(n),
+            None => self.head = Some(n),
+        }
+        Ok(())
+    }
+    /// 在指定位置删除元素，返回被删元素，当删除失败时返回None
+    pub fn delete(&mut self, i: usize) -> Option<T> {
+        let n = self.slot(i)?;
+        let next = self.node(n).next;
+        match i {
+            0 => self.head = next,
+            _ => {
+                let p = self.slot(i - 1)?;
+                self.node_mut(p).next = next;
+            },
+        }
+        Some(self.pool.release(n)?.value)
+    }
+    /// 在链表的头部压入一个元素，结点池已满时返回CapacityExceeded
+    pub fn push(&mut self, val: T) -> Result<(), CapacityExceeded<T>> {
+        self.insert(0, val).map_err(|e| CapacityExceeded(e.into_inner()))
+    }
+    /// 在链表的尾部追加元素，结点池已满时返回CapacityExceeded
+    pub fn push_back(&mut self, val: T) -> Result<(), CapacityExceeded<T>> {
+        self.insert(self.len(), val).map_err(|e| CapacityExceeded(e.into_inner()))
+    }
+    /// 弹出第一个元素，当链表为空时返回None
+    pub fn pop(&mut self) -> Option<T> {
+        self.delete(0)
+    }
+    /// 弹出最后一个元素，当链表为空时返回None
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.delete(self.len().checked_sub(1)?)
+    }
+    /// 获取第一个元素的不可变引用
+    pub fn front(&self) -> Option<&T> {
+        Some(&self.node(self.head?).value)
+    }
+    /// 获取第一个元素的可变引用
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        let h = self.head?;
+        Some(&mut self.node_mut(h).value)
+    }
+    /// 获取第i个元素的不可变引用
+    pub fn get(&self, i: usize) -> Option<&T> {
+        Some(&self.node(self.slot(i)?).value)
+    }
+    /// 获取第i个元素的可变引用
+    pub fn get_mut(&mut self, i: usize) -> Option<&mut T> {
+        let n = self.slot(i)?;
+        Some(&mut self.node_mut(n).value)
+    }
+    /// 生成不可变迭代器
+    pub fn iter(&self) -> StaticIter<'_, T, N> {
+        StaticIter {pool: &self.pool, cur: self.head}
+    }
+}
+impl<T, const N: usize> Default for StaticLink<T, N> {
+    fn default() -> Self {
+        StaticLink::new()
+    }
+}
+impl<T: fmt::Debug, const N: usize> fmt::Debug for StaticLink<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+/// StaticLink的不可变迭代器
+pub struct StaticIter<'a, T, const N: usize> {
+    pool: &'a FreeList<PoolNode<T>, N>,
+    cur: Option<Handle>,
+}
+impl<'a, T, const N: usize> Iterator for StaticIter<'a, T, N> {
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.pool.get(self.cur?)?;
+        self.cur = node.next;
+        Some(&node.value)
+    }
+}
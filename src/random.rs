@@ -0,0 +1,65 @@
+//! 基于rand的随机操作
+use crate::Link;
+use rand::Rng;
+
+impl<T> Link<T> {
+    /// 按权重随机选取一个元素，一次遍历：维护已见权重之和W，
+    /// 以w/W的概率用当前元素替换已选元素，每个元素最终被选中的概率与其权重成正比。
+    /// 权重不是有限正数（包括NaN和无穷大）的元素不会被选中，没有可选元素时返回None。
+    /// 权重之和超出f64的范围时按2的幂缩小已见权重之和，选中的概率不受影响
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// use rand::SeedableRng;
+    /// let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+    /// let tasks: Link<(&str, f64)> = link![("idle", 0.0), ("low", 1.0), ("high", 9.0)];
+    /// let mut high = 0;
+    /// for _ in 0..1000 {
+    ///     let (name, _) = tasks.choose_weighted(&mut rng, |t| t.1).unwrap();
+    ///     assert_ne!(*name, "idle");
+    ///     if *name == "high" {
+    ///         high += 1;
+    ///     }
+    /// }
+    /// assert!(850 < high && high < 950);
+    /// assert!(link![("none", 0.0)].choose_weighted(&mut rng, |t| t.1).is_none());
+    /// // 无穷大的权重被拒绝，巨大的权重之和不会溢出
+    /// let odd: Link<(&str, f64)> = link![("inf", f64::INFINITY), ("a", f64::MAX), ("b", f64::MAX)];
+    /// let mut b = 0;
+    /// for _ in 0..1000 {
+    ///     let (name, _) = odd.choose_weighted(&mut rng, |t| t.1).unwrap();
+    ///     assert_ne!(*name, "inf");
+    ///     if *name == "b" {
+    ///         b += 1;
+    ///     }
+    /// }
+    /// assert!(400 < b && b < 600);
+    /// assert!(link![("inf", f64::INFINITY)].choose_weighted(&mut rng, |t| t.1).is_none());
+    /// ```
+    pub fn choose_weighted<R, F>(&self, rng: &mut R, mut weight: F) -> Option<&T>
+    where
+        R: Rng + ?Sized,
+        F: FnMut(&T) -> f64 {
+        // 已见权重之和为total * scale
+        let mut total = 0.0;
+        let mut scale = 1.0;
+        let mut chosen = None;
+        for v in self {
+            let w = weight(v);
+            if !w.is_finite() || w <= 0.0 {
+                continue;
+            }
+            let mut w = w / scale;
+            while (total + w).is_infinite() {
+                total /= 2.0;
+                scale *= 2.0;
+                w /= 2.0;
+            }
+            total += w;
+            if rng.gen::<f64>() * total < w {
+                chosen = Some(v);
+            }
+        }
+        chosen
+    }
+}
@@ -0,0 +1,118 @@
+//! 基于链表的定长环形队列
+use crate::{CapacityExceeded, IntegrityError, Link, Overflow};
+use core::fmt;
+
+/// 定长先进先出队列，满时按策略覆盖最旧元素（Overflow::Evict）或拒绝最新元素（Overflow::Reject）。
+///
+/// 内部用两个链表实现：front按从旧到新保存较早的元素，back按从新到旧保存新入队的元素，
+/// 入队只在back的头部压入，为O(1)；front取空时把back反转后移入front，出队均摊O(1)
+/// # 例子
+/// ```
+/// use link::*;
+/// use link::ring::RingQueue;
+/// let mut q: RingQueue<isize> = RingQueue::overwriting(2);
+/// q.push(1).unwrap();
+/// q.push(2).unwrap();
+/// assert_eq!(q.push(3), Ok(Some(1)));
+/// assert_eq!(q.iter().collect::<Vec<_>>(), vec![&2, &3]);
+/// assert_eq!(q.pop(), Some(2));
+/// ```
+#[derive(Clone)]
+pub struct RingQueue<T> {
+    front: Link<T>,
+    back: Link<T>,
+    len: usize,
+    capacity: usize,
+    policy: Overflow,
+}
+impl<T> RingQueue<T> {
+    /// 创建容量为capacity的队列，并指定满时的处理策略
+    pub fn new(capacity: usize, policy: Overflow) -> Self {
+        RingQueue {front: Link::new(), back: Link::new(), len: 0, capacity, policy}
+    }
+    /// 创建满时覆盖最旧元素的队列
+    pub fn overwriting(capacity: usize) -> Self {
+        Self::new(capacity, Overflow::Evict)
+    }
+    /// 创建满时拒绝最新元素的队列
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// use link::ring::RingQueue;
+    /// let mut q: RingQueue<isize> = RingQueue::rejecting(1);
+    /// q.push(1).unwrap();
+    /// assert_eq!(q.push(2), Err(CapacityExceeded(2)));
+    /// assert_eq!(q.peek(), Some(&1));
+    /// ```
+    pub fn rejecting(capacity: usize) -> Self {
+        Self::new(capacity, Overflow::Reject)
+    }
+    /// 入队，返回被覆盖的最旧元素，O(1)（覆盖时为均摊O(1)）
+    pub fn push(&mut self, val: T) -> Result<Option<T>, CapacityExceeded<T>> {
+        let mut old = None;
+        if self.is_full() {
+            match self.policy {
+                Overflow::Reject => return Err(CapacityExceeded(val)),
+                //容量为0时新元素直接被覆盖
+                Overflow::Evict if self.capacity == 0 => return Ok(Some(val)),
+                Overflow::Evict => old = self.pop(),
+            }
+        }
+        match self.front.is_empty() {
+            true => self.front.push(val),
+            false => self.back.push(val),
+        }
+        self.len += 1;
+        Ok(old)
+    }
+    /// 出队最旧的元素，当队列为空时返回None，均摊O(1)
+    pub fn pop(&mut self) -> Option<T> {
+        let v = self.front.pop()?;
+        if self.front.is_empty() {
+            self.back.reverse();
+            self.front = self.back.take();
+        }
+        self.len -= 1;
+        Some(v)
+    }
+    /// 查看最旧的元素
+    pub fn peek(&self) -> Option<&T> {
+        self.front.front()
+    }
+    /// 获取元素个数
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    /// 判断队列是否为空
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    /// 判断队列是否已满
+    pub fn is_full(&self) -> bool {
+        self.len >= self.capacity
+    }
+    /// 获取容量
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+    /// 检查内部结构的完整性，用于调试
+    pub fn validate(&self) -> Result<(), IntegrityError> {
+        let actual = self.front.len() + self.back.len();
+        if actual != self.len {
+            return Err(IntegrityError::LengthMismatch {cached: self.len, actual});
+        }
+        if actual > self.capacity {
+            return Err(IntegrityError::OverCapacity {len: actual, capacity: self.capacity});
+        }
+        Ok(())
+    }
+    /// 生成从最旧到最新的不可变迭代器
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.front.iter().chain(self.back.iter().rev())
+    }
+}
+impl<T: fmt::Debug> fmt::Debug for RingQueue<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
@@ -0,0 +1,159 @@
+//! 与zeroize库的集成，保存敏感数据的链表
+use crate::{Iter, IterMut, Link};
+use core::fmt;
+use core::mem;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// 擦除每个元素后清空链表
+/// # 例子
+/// ```
+/// use link::*;
+/// use zeroize::Zeroize;
+/// let mut l: Link<[u8; 4]> = link![[1; 4], [2; 4]];
+/// l.zeroize();
+/// assert!(l.is_empty());
+/// ```
+impl<T: Zeroize> Zeroize for Link<T> {
+    fn zeroize(&mut self) {
+        let mut node = &mut *self;
+        while let Some(n) = node.0.as_mut() {
+            n.value.zeroize();
+            node = &mut n.next;
+        }
+        *self = Link::new();
+    }
+}
+/// 析构时擦除全部元素的链表，pop/delete释放结点之前也会先擦除结点中的数据，
+/// 被取出的元素由调用者负责擦除。不提供内部Link的引用，避免绕过擦除删除结点或把元素克隆到普通链表中，
+/// 可变迭代器只给出元素的可变引用，Debug不输出元素内容
+/// # 例子
+/// ```
+/// use link::secret::SecretLink;
+/// let mut keys = SecretLink::new();
+/// keys.push_back(String::from("hunter2"));
+/// keys.push_back(String::from("correct horse"));
+/// assert_eq!(keys.pop().as_deref(), Some("hunter2"));
+/// assert_eq!(keys.len(), 1);
+/// assert_eq!(format!("{:?}", keys), "SecretLink { len: 1, .. }");
+/// ```
+/// 不能把元素克隆到不会被擦除的普通链表中
+/// ```compile_fail
+/// use link::*;
+/// use link::secret::SecretLink;
+/// let mut keys = SecretLink::new();
+/// keys.push_back(String::from("hunter2"));
+/// let copy: Link<String> = keys.clone();
+/// ```
+pub struct SecretLink<T: Zeroize> {
+    link: Link<T>,
+}
+impl<T: Zeroize> SecretLink<T> {
+    /// 创建空链表
+    pub fn new() -> Self {
+        SecretLink {link: Link::new()}
+    }
+    /// 在链表的头部压入一个元素
+    pub fn push(&mut self, val: T) {
+        self.link.push(val);
+    }
+    /// 在链表的尾部追加元素
+    pub fn push_back(&mut self, val: T) {
+        self.link.push_back(val);
+    }
+    /// 生成不可变迭代器
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.link.iter()
+    }
+    /// 生成可变迭代器，只能修改元素，不能删除结点
+    /// # 例子
+    /// ```
+    /// use link::secret::SecretLink;
+    /// let mut pins = SecretLink::new();
+    /// pins.push_back([1u8, 2, 3, 4]);
+    /// for pin in pins.iter_mut() {
+    ///     pin.reverse();
+    /// }
+    /// assert_eq!(pins.front(), Some(&[4, 3, 2, 1]));
+    /// ```
+    pub fn iter_mut(&mut self) -> SecretIterMut<'_, T> {
+        SecretIterMut {inner: self.link.iter_mut()}
+    }
+    /// 获取链表长度
+    pub fn len(&self) -> usize {
+        self.link.len()
+    }
+    /// 判断链表是否为空
+    pub fn is_empty(&self) -> bool {
+        self.link.is_empty()
+    }
+    /// 第一个元素
+    pub fn front(&self) -> Option<&T> {
+        self.link.front()
+    }
+    /// 擦除并删除全部元素
+    pub fn clear(&mut self) {
+        self.link.zeroize();
+    }
+    /// 摘下slot指向的结点，用默认值覆盖结点中的数据后再释放
+    fn unlink(slot: &mut Link<T>) -> Option<T>
+    where
+        T: Default {
+        let mut node = slot.0.take()?;
+        *slot = node.next.take();
+        let value = mem::take(&mut node.value);
+        node.value.zeroize();
+        Some(value)
+    }
+    /// 弹出第一个元素，当链表为空时返回None
+    pub fn pop(&mut self) -> Option<T>
+    where
+        T: Default {
+        Self::unlink(&mut self.link)
+    }
+    /// 在指定位置删除元素，返回被删元素，当删除失败时返回None
+    pub fn delete(&mut self, i: usize) -> Option<T>
+    where
+        T: Default {
+        let mut slot = &mut self.link;
+        for _ in 0..i {
+            slot = &mut slot.0.as_mut()?.next;
+        }
+        Self::unlink(slot)
+    }
+}
+impl<T: Zeroize> Default for SecretLink<T> {
+    fn default() -> Self {
+        SecretLink::new()
+    }
+}
+impl<T: Zeroize> From<Link<T>> for SecretLink<T> {
+    fn from(link: Link<T>) -> Self {
+        SecretLink {link}
+    }
+}
+impl<T: Zeroize> Zeroize for SecretLink<T> {
+    fn zeroize(&mut self) {
+        self.link.zeroize();
+    }
+}
+impl<T: Zeroize> Drop for SecretLink<T> {
+    fn drop(&mut self) {
+        self.link.zeroize();
+    }
+}
+impl<T: Zeroize> ZeroizeOnDrop for SecretLink<T> {}
+/// SecretLink的可变迭代器，只给出元素的可变引用
+pub struct SecretIterMut<'a, T> {
+    inner: IterMut<'a, T>,
+}
+impl<'a, T> Iterator for SecretIterMut<'a, T> {
+    type Item = &'a mut T;
+    fn next(&mut self) -> Option<&'a mut T> {
+        self.inner.next()
+    }
+}
+impl<T: Zeroize> fmt::Debug for SecretLink<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SecretLink").field("len", &self.link.len()).finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,57 @@
+//! 与serde库的集成，链表序列化为序列
+use crate::{Link, LinkBuilder};
+use core::fmt;
+use core::marker::PhantomData;
+use serde::de::{Deserialize, Deserializer, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeSeq, Serializer};
+
+/// 按顺序序列化为序列，与Vec<T>的格式相同，序列的长度预先给出，
+/// 可以用于bincode等需要长度前缀的格式
+/// # 例子
+/// ```
+/// use link::*;
+/// let l: Link<isize> = link![1, 2, 3];
+/// assert_eq!(serde_json::to_string(&l).unwrap(), "[1,2,3]");
+/// let nested: Link<Link<&str>> = link![link!["a"], link![]];
+/// assert_eq!(serde_json::to_string(&nested).unwrap(), r#"[["a"],[]]"#);
+/// let bytes = bincode::serialize(&l).unwrap();
+/// assert_eq!(bytes, bincode::serialize(&vec![1isize, 2, 3]).unwrap());
+/// let back: Link<isize> = bincode::deserialize(&bytes).unwrap();
+/// assert_eq!(format!("{:?}", back), "[1, 2, 3]");
+/// ```
+impl<T: Serialize> Serialize for Link<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for v in self {
+            seq.serialize_element(v)?;
+        }
+        seq.end()
+    }
+}
+/// 从序列反序列化，直接逐个追加到链表，不经过Vec
+/// # 例子
+/// ```
+/// use link::*;
+/// let l: Link<String> = serde_json::from_str(r#"["x", "y"]"#).unwrap();
+/// assert_eq!(format!("{:?}", l), r#"["x", "y"]"#);
+/// assert!(serde_json::from_str::<Link<u8>>("[1, 256]").is_err());
+/// ```
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Link<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(LinkVisitor(PhantomData))
+    }
+}
+struct LinkVisitor<T>(PhantomData<T>);
+impl<'de, T: Deserialize<'de>> Visitor<'de> for LinkVisitor<T> {
+    type Value = Link<T>;
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a sequence")
+    }
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Link<T>, A::Error> {
+        let mut builder = LinkBuilder::new();
+        while let Some(v) = seq.next_element()? {
+            builder.push(v);
+        }
+        Ok(builder.finish())
+    }
+}
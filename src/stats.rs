@@ -0,0 +1,120 @@
+//! 基于链表环形队列的滑动窗口统计
+use crate::ring::RingQueue;
+use alloc::collections::VecDeque;
+use core::ops::{Add, Sub};
+
+/// 最近N个样本的滑动窗口，sum/min/max均为O(1)，push为均摊O(1)，
+/// 样本保存在容量为N、满时覆盖最旧样本的RingQueue中。
+/// 和通过加新减旧增量维护，浮点数长时间运行会累积舍入误差，可以定期用recompute校正
+/// # 例子
+/// ```
+/// use link::stats::Moving;
+/// let mut m: Moving<i32, 3> = Moving::new();
+/// for v in [4, 1, 7] {
+///     m.push(v);
+/// }
+/// assert_eq!((m.sum(), m.min(), m.max()), (12, Some(1), Some(7)));
+/// assert_eq!(m.push(2), Some(4));
+/// assert_eq!((m.sum(), m.min(), m.max()), (10, Some(1), Some(7)));
+/// assert_eq!(m.mean(), Some(10.0 / 3.0));
+/// ```
+pub struct Moving<T, const N: usize> {
+    window: RingQueue<T>,
+    sum: T,
+    // 单调队列，元素为(序号, 样本)，队首为窗口内的最小/最大值
+    mins: VecDeque<(usize, T)>,
+    maxs: VecDeque<(usize, T)>,
+    // 已放入的样本总数，即下一个样本的序号
+    seq: usize,
+}
+impl<T, const N: usize> Moving<T, N>
+where
+    T: Copy + PartialOrd + Default + Add<Output = T> + Sub<Output = T> {
+    /// 创建空窗口
+    pub fn new() -> Self {
+        Moving {
+            window: RingQueue::overwriting(N),
+            sum: T::default(),
+            mins: VecDeque::new(),
+            maxs: VecDeque::new(),
+            seq: 0,
+        }
+    }
+    /// 放入一个样本，窗口已满时淘汰最旧的样本并返回它
+    pub fn push(&mut self, x: T) -> Option<T> {
+        let old = self.window.push(x).unwrap_or(None);
+        self.sum = self.sum + x;
+        if let Some(o) = old {
+            self.sum = self.sum - o;
+        }
+        let seq = self.seq;
+        self.seq += 1;
+        Self::slide(&mut self.mins, seq, x, |b, x| b < x);
+        Self::slide(&mut self.maxs, seq, x, |b, x| b > x);
+        old
+    }
+    /// 维护单调队列：从队尾去掉不再可能成为最值的样本，从队首去掉已离开窗口的样本
+    fn slide(q: &mut VecDeque<(usize, T)>, seq: usize, x: T, keep: fn(T, T) -> bool) {
+        while q.back().is_some_and(|&(_, b)| !keep(b, x)) {
+            q.pop_back();
+        }
+        q.push_back((seq, x));
+        while q.front().is_some_and(|&(s, _)| s + N <= seq) {
+            q.pop_front();
+        }
+    }
+    /// 窗口内样本的和，空窗口为T::default()
+    pub fn sum(&self) -> T {
+        self.sum
+    }
+    /// 窗口内的最小值
+    pub fn min(&self) -> Option<T> {
+        self.mins.front().map(|&(_, v)| v)
+    }
+    /// 窗口内的最大值
+    pub fn max(&self) -> Option<T> {
+        self.maxs.front().map(|&(_, v)| v)
+    }
+    /// 窗口内样本的平均值，空窗口返回None
+    pub fn mean(&self) -> Option<f64>
+    where
+        T: Into<f64> {
+        match self.len() {
+            0 => None,
+            n => Some(self.sum.into() / n as f64),
+        }
+    }
+    /// 按窗口内的样本重新计算和，消除累积的舍入误差
+    pub fn recompute(&mut self) {
+        self.sum = self.window.iter().fold(T::default(), |s, &v| s + v);
+    }
+    /// 清空窗口
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+}
+impl<T, const N: usize> Moving<T, N> {
+    /// 窗口内的样本个数
+    pub fn len(&self) -> usize {
+        self.window.len()
+    }
+    /// 判断窗口是否为空
+    pub fn is_empty(&self) -> bool {
+        self.window.is_empty()
+    }
+    /// 判断窗口是否已满
+    pub fn is_full(&self) -> bool {
+        self.window.is_full()
+    }
+    /// 从旧到新遍历窗口内的样本
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.window.iter()
+    }
+}
+impl<T, const N: usize> Default for Moving<T, N>
+where
+    T: Copy + PartialOrd + Default + Add<Output = T> + Sub<Output = T> {
+    fn default() -> Self {
+        Moving::new()
+    }
+}
@@ -0,0 +1,104 @@
+//! 基于环形桶数组的时间轮定时器
+use crate::pool::{FreeList, Handle};
+use crate::{CapacityExceeded, Link};
+
+/// 时间轮，时间以整数刻度表示。SLOTS个桶组成一圈，到期刻度为d的定时器放在第d % SLOTS个桶中，
+/// 每个桶是保存句柄的链表；定时器本身（到期刻度和元素）放在容量为CAP的FreeList结点池中，
+/// 插入和取消都是O(1)。桶中的句柄结点在insert时分配、在所在的桶被检查时释放，
+/// advance返回的链表也为每个到期元素分配一个结点。
+///
+/// advance每前进一个刻度只检查一个桶，桶中尚未到期的（属于之后几圈的）定时器留在原处，
+/// 已取消的定时器在所在的桶被检查时顺带清除。一次前进不超过一圈时，
+/// 到期的元素按到期刻度的顺序返回，同一刻度内按插入顺序返回
+/// # 例子
+/// ```
+/// use link::timerwheel::TimerWheel;
+/// let mut wheel: TimerWheel<&str, 8, 16> = TimerWheel::new();
+/// wheel.insert(3, "retry").unwrap();
+/// let idle = wheel.insert(5, "idle").unwrap();
+/// wheel.insert(12, "keepalive").unwrap();
+/// wheel.insert(5, "flush").unwrap();
+/// assert_eq!(format!("{:?}", wheel.advance(4)), r#"["retry"]"#);
+/// // 连接恢复活跃，取消空闲超时
+/// assert_eq!(wheel.cancel(idle), Some("idle"));
+/// assert_eq!(format!("{:?}", wheel.advance(6)), r#"["flush"]"#);
+/// // 刻度12与刻度4在同一个桶中，要到第二圈才到期
+/// assert_eq!(format!("{:?}", wheel.advance(12)), r#"["keepalive"]"#);
+/// assert!(wheel.is_empty());
+/// ```
+pub struct TimerWheel<T, const SLOTS: usize, const CAP: usize> {
+    buckets: [Link<Handle>; SLOTS],
+    timers: FreeList<(u64, T), CAP>,
+    now: u64,
+}
+impl<T, const SLOTS: usize, const CAP: usize> TimerWheel<T, SLOTS, CAP> {
+    /// 创建当前刻度为0的空时间轮
+    /// # Panics
+    /// 当SLOTS为0时恐慌
+    pub fn new() -> Self {
+        assert!(SLOTS != 0, "timer wheel must have at least one slot");
+        TimerWheel {buckets: core::array::from_fn(|_| Link::new()), timers: FreeList::new(), now: 0}
+    }
+    /// 当前刻度
+    pub fn now(&self) -> u64 {
+        self.now
+    }
+    /// 等待中的定时器个数
+    pub fn len(&self) -> usize {
+        self.timers.len()
+    }
+    /// 判断是否没有等待中的定时器
+    pub fn is_empty(&self) -> bool {
+        self.timers.is_empty()
+    }
+    /// 最多可以同时等待的定时器个数
+    pub fn capacity(&self) -> usize {
+        CAP
+    }
+    /// 放入在刻度deadline到期的元素，返回可以用来取消的句柄，
+    /// deadline不晚于当前刻度时在下一次advance时到期，结点池已满时返回携带原值的CapacityExceeded
+    pub fn insert(&mut self, deadline: u64, item: T) -> Result<Handle, CapacityExceeded<T>> {
+        let h = self.timers.acquire((deadline, item)).map_err(|CapacityExceeded((_, v))| CapacityExceeded(v))?;
+        let tick = deadline.max(self.now + 1);
+        self.buckets[(tick % SLOTS as u64) as usize].push(h);
+        Ok(h)
+    }
+    /// 取消定时器并返回元素，定时器已到期或已取消时返回None
+    pub fn cancel(&mut self, h: Handle) -> Option<T> {
+        self.timers.release(h).map(|(_, v)| v)
+    }
+    /// 定时器的到期刻度，定时器已到期或已取消时返回None
+    pub fn deadline(&self, h: Handle) -> Option<u64> {
+        self.timers.get(h).map(|(d, _)| *d)
+    }
+    /// 把当前刻度前进到now，移出在此期间到期的全部元素，now早于当前刻度时返回空链表
+    pub fn advance(&mut self, now: u64) -> Link<T> {
+        let mut expired = Link::new();
+        if now <= self.now {
+            return expired;
+        }
+        let ticks = (now - self.now).min(SLOTS as u64);
+        // 桶中新插入的在前，从最晚的刻度开始逐个压入头部，结果按刻度和插入顺序排列
+        for tick in (self.now + 1..=self.now + ticks).rev() {
+            let mut slot = &mut self.buckets[(tick % SLOTS as u64) as usize];
+            while let Some(&h) = slot.front() {
+                match self.timers.get(h) {
+                    Some((d, _)) if *d > now => {
+                        slot = &mut slot.0.as_mut().unwrap().next;
+                        continue;
+                    }
+                    Some(_) => expired.push(self.timers.release(h).unwrap().1),
+                    None => {}
+                }
+                slot.pop();
+            }
+        }
+        self.now = now;
+        expired
+    }
+}
+impl<T, const SLOTS: usize, const CAP: usize> Default for TimerWheel<T, SLOTS, CAP> {
+    fn default() -> Self {
+        TimerWheel::new()
+    }
+}
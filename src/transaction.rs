@@ -0,0 +1,134 @@
+//! 可回滚的批量修改
+use crate::Link;
+use alloc::vec::Vec;
+
+/// 撤销一次修改所需的信息
+enum Undo<T> {
+    /// 撤销插入：删除该位置的元素
+    Delete(usize),
+    /// 撤销删除：把元素插回该位置
+    Insert(usize, T),
+    /// 撤销赋值：恢复原来的值
+    Set(usize, T),
+}
+/// 事务，修改直接作用于链表并记录撤销日志，
+/// 事务未提交就被丢弃时（闭包返回Err或发生恐慌）按相反顺序撤销全部修改
+pub struct Transaction<'a, T> {
+    link: &'a mut Link<T>,
+    undo: Vec<Undo<T>>,
+}
+impl<T> Transaction<'_, T> {
+    /// 查看事务中的链表当前状态
+    pub fn as_link(&self) -> &Link<T> {
+        self.link
+    }
+    /// 获取链表长度
+    pub fn len(&self) -> usize {
+        self.link.len()
+    }
+    /// 判断链表是否为空
+    pub fn is_empty(&self) -> bool {
+        self.link.is_empty()
+    }
+    /// 获取第i个元素
+    pub fn get(&self, i: usize) -> Option<&T> {
+        self.link.get(i).map(|n| &n.value)
+    }
+    /// 在指定位置插入元素，位置超出范围时返回false且不做修改
+    pub fn insert(&mut self, i: usize, val: T) -> bool {
+        let ok = self.link.insert(i, val).is_some();
+        if ok {
+            self.undo.push(Undo::Delete(i));
+        }
+        ok
+    }
+    /// 在链表的头部压入一个元素
+    pub fn push(&mut self, val: T) {
+        self.insert(0, val);
+    }
+    /// 在链表的尾部追加元素
+    pub fn push_back(&mut self, val: T) {
+        let i = self.len();
+        self.insert(i, val);
+    }
+    /// 删除指定位置的元素，位置超出范围时返回false，
+    /// 被删除的元素保留到事务结束，以便回滚
+    pub fn delete(&mut self, i: usize) -> bool {
+        match self.link.delete(i) {
+            Some(v) => {
+                self.undo.push(Undo::Insert(i, v));
+                true
+            },
+            None => false,
+        }
+    }
+    /// 替换第i个元素，位置超出范围时返回false
+    pub fn set(&mut self, i: usize, val: T) -> bool {
+        match self.link.get_mut(i) {
+            Some(n) => {
+                let old = core::mem::replace(&mut n.value, val);
+                self.undo.push(Undo::Set(i, old));
+                true
+            },
+            None => false,
+        }
+    }
+    /// 按相反顺序撤销全部修改
+    fn rollback(&mut self) {
+        while let Some(u) = self.undo.pop() {
+            match u {
+                Undo::Delete(i) => {
+                    self.link.delete(i);
+                },
+                Undo::Insert(i, v) => {
+                    self.link.insert(i, v);
+                },
+                Undo::Set(i, v) => {
+                    if let Some(n) = self.link.get_mut(i) {
+                        n.value = v;
+                    }
+                },
+            }
+        }
+    }
+}
+impl<T> Drop for Transaction<'_, T> {
+    fn drop(&mut self) {
+        self.rollback();
+    }
+}
+impl<T> Link<T> {
+    /// 在事务中执行一组修改，闭包返回Ok时全部生效，返回Err或发生恐慌时全部回滚
+    /// # 例子
+    /// ```
+    /// use link::*;
+    /// let mut l: Link<isize> = link![1, 2, 3];
+    /// let r: Result<(), &str> = l.transaction(|tx| {
+    ///     tx.insert(0, 0);
+    ///     tx.delete(2);
+    ///     if !tx.set(5, 9) {
+    ///         return Err("out of range");
+    ///     }
+    ///     Ok(())
+    /// });
+    /// assert!(r.is_err());
+    /// assert_eq!(format!("{:?}", l), "[1, 2, 3]");
+    /// let r: Result<usize, ()> = l.transaction(|tx| {
+    ///     tx.push_back(4);
+    ///     tx.delete(0);
+    ///     Ok(tx.len())
+    /// });
+    /// assert_eq!(r, Ok(3));
+    /// assert_eq!(format!("{:?}", l), "[2, 3, 4]");
+    /// ```
+    pub fn transaction<R, E, F>(&mut self, f: F) -> Result<R, E>
+    where
+        F: FnOnce(&mut Transaction<'_, T>) -> Result<R, E> {
+        let mut tx = Transaction {link: self, undo: Vec::new()};
+        let r = f(&mut tx);
+        if r.is_ok() {
+            tx.undo.clear();
+        }
+        r
+    }
+}
@@ -0,0 +1,79 @@
+//! 存放弱引用的链表
+use crate::{Iter, Link};
+use alloc::rc::{Rc, Weak};
+
+/// 存放弱引用的链表，不会延长元素的生命周期，已释放的元素在迭代时被跳过
+/// # 例子
+/// ```
+/// use std::rc::Rc;
+/// use link::*;
+/// let a = Rc::new(1);
+/// let b = Rc::new(2);
+/// let mut l = WeakLink::new();
+/// l.push(&a);
+/// l.push(&b);
+/// drop(a);
+/// assert_eq!(l.iter_alive().map(|v| *v).collect::<Vec<_>>(), vec![2]);
+/// assert_eq!(l.prune(), 1);
+/// assert_eq!(l.len(), 1);
+/// ```
+#[derive(Clone)]
+pub struct WeakLink<T> {
+    link: Link<Weak<T>>,
+}
+impl<T> WeakLink<T> {
+    /// 创建空链表
+    pub fn new() -> Self {
+        WeakLink {link: Link::new()}
+    }
+    /// 在头部压入元素的弱引用
+    pub fn push(&mut self, val: &Rc<T>) {
+        self.link.push(Rc::downgrade(val));
+    }
+    /// 在尾部追加元素的弱引用
+    pub fn push_back(&mut self, val: &Rc<T>) {
+        self.link.push_back(Rc::downgrade(val));
+    }
+    /// 获取结点个数，包括已释放的元素
+    pub fn len(&self) -> usize {
+        self.link.len()
+    }
+    /// 判断链表是否为空
+    pub fn is_empty(&self) -> bool {
+        self.link.is_empty()
+    }
+    /// 生成只返回仍然存活的元素的迭代器
+    pub fn iter_alive(&self) -> IterAlive<'_, T> {
+        IterAlive {data: self.link.iter()}
+    }
+    /// 删除已释放的元素的结点，返回删除的个数
+    pub fn prune(&mut self) -> usize {
+        let mut removed = 0;
+        let mut node = &mut self.link;
+        while let Some(n) = node.0.as_ref() {
+            if n.value.strong_count() == 0 {
+                let n = node.0.take().unwrap();
+                *node = n.next;
+                removed += 1;
+            } else {
+                node = &mut node.0.as_mut().unwrap().next;
+            }
+        }
+        removed
+    }
+}
+impl<T> Default for WeakLink<T> {
+    fn default() -> Self {
+        WeakLink::new()
+    }
+}
+/// 存活元素的迭代器
+pub struct IterAlive<'a, T> {
+    data: Iter<'a, Weak<T>>,
+}
+impl<'a, T> Iterator for IterAlive<'a, T> {
+    type Item = Rc<T>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.data.by_ref().find_map(Weak::upgrade)
+    }
+}